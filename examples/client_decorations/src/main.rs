@@ -1,4 +1,5 @@
 use iced::{
+    color,
     widget::{container, text},
     Application, Length,
 };
@@ -7,6 +8,7 @@ fn main() {
     MenuTester::run(iced::Settings {
         window: iced::window::Settings {
             decorations: false,
+            transparent: true,
             ..iced::window::Settings::default()
         },
         ..iced::Settings::default()
@@ -63,13 +65,18 @@ impl Application for MenuTester {
 
     fn view(&self) -> iced::Element<'_, Self::Message, Self::Theme, iced::Renderer> {
         let content = container(text("Hello, World!"))
+            .width(Length::Fill)
             .height(Length::Fill)
             .center_x()
             .center_y();
 
         iced_decorator::window::Window::new()
             .title(self.title.clone())
-            .content(content).panel_theme(iced_decorator::PanelTheme::DARK)
+            .body(content)
+            .panel_theme(iced_decorator::PanelTheme::DARK)
+            .frame(iced_decorator::frame::Frame::default().background(color!(0x282a36)))
+            .resize_area(iced_decorator::ResizeArea::Outside(8.0))
+            .icons(iced_decorator::svgs::IconPack::Fluent)
             .into()
     }
 }
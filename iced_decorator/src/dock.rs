@@ -0,0 +1,421 @@
+use std::rc::Rc;
+
+use iced::{
+    widget::{column, text, Column, Row},
+    Element, Length, Size,
+};
+
+use crate::panel_button::{CaptionIcon, PanelButtons};
+use crate::resizable::{resizable, Edges};
+use crate::tear_off::{tear_off, TearOffEvent};
+use crate::theming::focus::FocusThemer;
+use crate::window::TitleBarMetrics;
+use crate::{IconRenderer, PanelButton, PanelTheme};
+
+/// smallest size of the docked panels along their splitter
+const MIN_PANEL_SIZE: f32 = 80.0;
+/// padding of the panel headers
+const HEADER_PADDING: u16 = 4;
+
+/// the side of the body a panel is docked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Left,
+    Right,
+    Bottom,
+}
+
+/// a tool panel of the [`DockLayout`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockPanel {
+    pub id: String,
+    pub side: Side,
+    /// only the icon of the panel is shown in the strip of its side
+    pub collapsed: bool,
+}
+
+/// the panels docked around the body and the size of each side, kept by the
+/// app and serializable with the `serde` feature so it can be restored
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockLayout {
+    pub panels: Vec<DockPanel>,
+    /// width of the left side
+    pub left: f32,
+    /// width of the right side
+    pub right: f32,
+    /// height of the bottom side
+    pub bottom: f32,
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self {
+            panels: Vec::new(),
+            left: 250.0,
+            right: 250.0,
+            bottom: 200.0,
+        }
+    }
+}
+
+/// the messages of a [`Dock`], pass them to [`DockLayout::update`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DockEvent {
+    /// the splitter of the side was dragged to the size
+    Resize(Side, f32),
+    /// collapse or expand the panel with the id
+    Toggle(String),
+}
+
+impl DockLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// docks a panel after the other panels of the side
+    pub fn panel(mut self, id: impl Into<String>, side: Side) -> Self {
+        self.panels.push(DockPanel {
+            id: id.into(),
+            side,
+            collapsed: false,
+        });
+        self
+    }
+
+    /// the panels of the side in order
+    pub fn side(&self, side: Side) -> impl Iterator<Item = &DockPanel> {
+        self.panels.iter().filter(move |panel| panel.side == side)
+    }
+
+    pub fn size(&self, side: Side) -> f32 {
+        match side {
+            Side::Left => self.left,
+            Side::Right => self.right,
+            Side::Bottom => self.bottom,
+        }
+    }
+
+    pub fn update(&mut self, event: DockEvent) {
+        match event {
+            DockEvent::Resize(Side::Left, size) => self.left = size,
+            DockEvent::Resize(Side::Right, size) => self.right = size,
+            DockEvent::Resize(Side::Bottom, size) => self.bottom = size,
+            DockEvent::Toggle(id) => {
+                if let Some(panel) = self.panels.iter_mut().find(|panel| panel.id == id) {
+                    panel.collapsed = !panel.collapsed;
+                }
+            }
+        }
+    }
+}
+
+/// the title, icon and content of a docked panel
+pub struct DockContent<'a, Message, Theme, Renderer> {
+    title: String,
+    icon: CaptionIcon,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> DockContent<'a, Message, Theme, Renderer> {
+    pub fn new(
+        title: impl Into<String>,
+        icon: impl Into<CaptionIcon>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        DockContent {
+            title: title.into(),
+            icon: icon.into(),
+            content: content.into(),
+        }
+    }
+}
+
+pub fn dock<'a, Message, Theme, Renderer>(
+    layout: &DockLayout,
+    body: impl Into<Element<'a, Message, Theme, Renderer>>,
+    view: impl Fn(&DockPanel) -> DockContent<'a, Message, Theme, Renderer>,
+    on_event: impl 'a + Fn(DockEvent) -> Message,
+) -> Dock<'a, Message, Theme, Renderer> {
+    Dock::new(layout, body, view, on_event)
+}
+
+/// tool panels docked to the left, right and bottom of a body, meant as the
+/// body of the [`Window`](crate::window::Window) under its title bar
+///
+/// every side has a strip with the icons of its panels that collapse and
+/// expand them, the expanded panels are resized by the splitter on their
+/// inner edge
+pub struct Dock<'a, Message, Theme, Renderer> {
+    body: Element<'a, Message, Theme, Renderer>,
+    panels: Vec<(DockPanel, DockContent<'a, Message, Theme, Renderer>)>,
+    sizes: [f32; 3],
+    on_event: Rc<dyn Fn(DockEvent) -> Message + 'a>,
+    on_tear_off: Option<Rc<dyn Fn(String, TearOffEvent) -> Message + 'a>>,
+    panel_theme: PanelTheme,
+    inactive_panel_theme: Option<PanelTheme>,
+    panel_buttons: PanelButtons,
+}
+
+impl<'a, Message, Theme, Renderer> Dock<'a, Message, Theme, Renderer> {
+    pub fn new(
+        layout: &DockLayout,
+        body: impl Into<Element<'a, Message, Theme, Renderer>>,
+        view: impl Fn(&DockPanel) -> DockContent<'a, Message, Theme, Renderer>,
+        on_event: impl 'a + Fn(DockEvent) -> Message,
+    ) -> Self {
+        Dock {
+            body: body.into(),
+            panels: layout
+                .panels
+                .iter()
+                .map(|panel| (panel.clone(), view(panel)))
+                .collect(),
+            sizes: [layout.left, layout.right, layout.bottom],
+            on_event: Rc::new(on_event),
+            on_tear_off: None,
+            panel_theme: PanelTheme::default(),
+            inactive_panel_theme: None,
+            panel_buttons: PanelButtons::default().metrics(TitleBarMetrics::COMPACT),
+        }
+    }
+
+    pub fn panel_theme(mut self, panel_theme: PanelTheme) -> Self {
+        self.panel_theme = panel_theme;
+        self
+    }
+
+    /// theme while the window is unfocused, defaults to [`PanelTheme::inactive`]
+    pub fn inactive_panel_theme(mut self, panel_theme: PanelTheme) -> Self {
+        self.inactive_panel_theme = Some(panel_theme);
+        self
+    }
+
+    /// size and icons of the strip buttons, compact by default
+    pub fn panel_buttons(mut self, panel_buttons: PanelButtons) -> Self {
+        self.panel_buttons = panel_buttons;
+        self
+    }
+
+    /// published with the id of a panel and [`TearOffEvent::DraggedOut`] when its
+    /// header is dragged out of the window, see
+    /// [`tear_off::locate`](crate::tear_off::locate)
+    pub fn on_tear_off(
+        mut self,
+        on_tear_off: impl 'a + Fn(String, TearOffEvent) -> Message,
+    ) -> Self {
+        self.on_tear_off = Some(Rc::new(on_tear_off));
+        self
+    }
+
+    fn view(self) -> Element<'a, Message, Theme, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a + IconRenderer + iced::advanced::text::Renderer<Font = iced::Font>,
+        Theme: 'a,
+    {
+        let active = self.panel_theme;
+        let inactive = self
+            .inactive_panel_theme
+            .unwrap_or_else(|| active.inactive());
+        let themed = |content: Element<'a, Message, PanelTheme, Renderer>| {
+            Element::from(FocusThemer::new(active, inactive, content))
+        };
+
+        let mut strips: [Vec<Element<'a, Message, PanelTheme, Renderer>>; 3] = Default::default();
+        let mut areas: [Vec<Element<'a, Message, Theme, Renderer>>; 3] = Default::default();
+
+        for (panel, content) in self.panels {
+            let side = panel.side as usize;
+            let style = if panel.collapsed {
+                PanelButton::Custom
+            } else {
+                PanelButton::Toggled
+            };
+
+            strips[side].push(
+                self.panel_buttons
+                    .button(content.icon)
+                    .style(style)
+                    .on_press((self.on_event)(DockEvent::Toggle(panel.id.clone())))
+                    .into(),
+            );
+
+            if !panel.collapsed {
+                let header = Row::with_children(vec![text(content.title).size(12).into()])
+                    .padding(HEADER_PADDING);
+
+                let header = match &self.on_tear_off {
+                    Some(on_tear_off) => {
+                        let on_tear_off = on_tear_off.clone();
+                        let id = panel.id.clone();
+                        tear_off(themed(header.into()), move |event| {
+                            on_tear_off(id.clone(), event)
+                        })
+                        .into()
+                    }
+                    None => themed(header.into()),
+                };
+
+                areas[side].push(column![header, content.content].into());
+            }
+        }
+
+        let [left_strip, right_strip, bottom_strip] = strips;
+        let [left, right, bottom] = areas;
+        let [left_size, right_size, bottom_size] = self.sizes;
+
+        // the splitter of a side is the edge that faces the body
+        let area = |side: Side, panels: Vec<Element<'a, Message, Theme, Renderer>>| {
+            if panels.is_empty() {
+                return None;
+            }
+
+            let on_event = self.on_event.clone();
+            let panels = resizable(
+                match side {
+                    Side::Bottom => Element::from(Row::with_children(panels).height(Length::Fill)),
+                    _ => Column::with_children(panels).width(Length::Fill).into(),
+                },
+                move |size: Size| {
+                    on_event(DockEvent::Resize(
+                        side,
+                        match side {
+                            Side::Bottom => size.height,
+                            _ => size.width,
+                        },
+                    ))
+                },
+            );
+
+            Some(Element::from(match side {
+                Side::Left => panels
+                    .width(left_size)
+                    .edges(Edges::RIGHT)
+                    .min_size(Size::new(MIN_PANEL_SIZE, 0.0)),
+                Side::Right => panels
+                    .width(right_size)
+                    .edges(Edges::LEFT)
+                    .min_size(Size::new(MIN_PANEL_SIZE, 0.0)),
+                Side::Bottom => panels
+                    .height(bottom_size)
+                    .edges(Edges::TOP)
+                    .min_size(Size::new(0.0, MIN_PANEL_SIZE)),
+            }))
+        };
+        let strip = |buttons: Vec<Element<'a, Message, PanelTheme, Renderer>>, side: Side| {
+            if buttons.is_empty() {
+                return None;
+            }
+
+            Some(themed(match side {
+                Side::Bottom => Row::with_children(buttons).into(),
+                _ => Column::with_children(buttons).into(),
+            }))
+        };
+
+        let center = Column::with_children(
+            std::iter::once(self.body)
+                .chain(area(Side::Bottom, bottom))
+                .chain(strip(bottom_strip, Side::Bottom)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill);
+
+        Row::with_children(
+            strip(left_strip, Side::Left)
+                .into_iter()
+                .chain(area(Side::Left, left))
+                .chain(std::iter::once(center.into()))
+                .chain(area(Side::Right, right))
+                .chain(strip(right_strip, Side::Right)),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Dock<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + IconRenderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a,
+{
+    fn from(dock: Dock<'a, Message, Theme, Renderer>) -> Self {
+        dock.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> DockLayout {
+        DockLayout::new()
+            .panel("files", Side::Left)
+            .panel("outline", Side::Left)
+            .panel("terminal", Side::Bottom)
+    }
+
+    #[test]
+    fn resize_sets_the_size_of_the_side() {
+        let mut layout = layout();
+        layout.update(DockEvent::Resize(Side::Left, 320.0));
+        layout.update(DockEvent::Resize(Side::Bottom, 150.0));
+
+        assert_eq!(layout.size(Side::Left), 320.0);
+        assert_eq!(layout.size(Side::Right), DockLayout::default().right);
+        assert_eq!(layout.size(Side::Bottom), 150.0);
+    }
+
+    #[test]
+    fn toggle_collapses_and_expands_the_panel() {
+        let mut layout = layout();
+        let collapsed = |layout: &DockLayout| {
+            layout
+                .panels
+                .iter()
+                .filter(|panel| panel.collapsed)
+                .map(|panel| panel.id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        layout.update(DockEvent::Toggle("outline".to_owned()));
+        assert_eq!(collapsed(&layout), vec!["outline".to_owned()]);
+
+        layout.update(DockEvent::Toggle("outline".to_owned()));
+        assert!(collapsed(&layout).is_empty());
+
+        // unknown panels are ignored
+        layout.update(DockEvent::Toggle("missing".to_owned()));
+        assert!(collapsed(&layout).is_empty());
+    }
+
+    #[test]
+    fn side_keeps_the_order_of_the_panels() {
+        let layout = layout();
+        let ids: Vec<_> = layout
+            .side(Side::Left)
+            .map(|panel| panel.id.as_str())
+            .collect();
+
+        assert_eq!(ids, vec!["files", "outline"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_serde() {
+        let mut layout = layout();
+        layout.update(DockEvent::Toggle("terminal".to_owned()));
+        layout.update(DockEvent::Resize(Side::Right, 300.0));
+
+        let json = serde_json::to_string(&layout).unwrap();
+        let restored: DockLayout = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, layout);
+    }
+}
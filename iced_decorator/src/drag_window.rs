@@ -1,27 +1,128 @@
 use iced::{
-    advanced::{layout, widget::Tree, Text, Widget},
-    alignment, mouse, touch, Element, Event, Length, Point, Size,
+    advanced::{
+        layout, overlay,
+        text::{LineHeight, Paragraph, Shaping},
+        widget::{tree, Operation, Tree},
+        Text, Widget,
+    },
+    alignment, event, font, mouse, touch, Color, Element, Event, Font, Length, Pixels, Point,
+    Rectangle, Size, Vector,
 };
 
-/// An amount of empty space.
+/// space kept free on both sides of the title
+const TITLE_PADDING: f32 = 8.0;
+
+/// how a title that is too long for the title bar is shortened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Truncation {
+    /// cut the end of the title and add an ellipsis
+    #[default]
+    End,
+    /// cut the middle of the title and add an ellipsis
+    Middle,
+    /// clip the title at the edges of the title bar
+    None,
+}
+
+/// font, color and placement of the title
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TitleStyle {
+    /// font of the title, defaults to the font of the renderer
+    pub font: Option<Font>,
+    /// weight of the title font
+    pub weight: Option<font::Weight>,
+    /// size of the title, defaults to the text size of the renderer
+    pub size: Option<Pixels>,
+    /// color of the title, defaults to the title color of the panel theme
+    pub color: Option<Color>,
+    /// horizontal alignment of the title in the free space of the title bar
+    pub alignment: alignment::Horizontal,
+    /// how the title is shortened when it does not fit
+    pub truncation: Truncation,
+    /// center the title on the whole window instead of the free space like macos,
+    /// falls back to the free space when the title would overlap other content
+    pub center_on_window: bool,
+}
+
+impl TitleStyle {
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn weight(mut self, weight: font::Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn alignment(mut self, alignment: alignment::Horizontal) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    pub fn center_on_window(mut self, center_on_window: bool) -> Self {
+        self.center_on_window = center_on_window;
+        self
+    }
+}
+
+impl Default for TitleStyle {
+    fn default() -> Self {
+        Self {
+            font: None,
+            weight: None,
+            size: None,
+            color: None,
+            alignment: alignment::Horizontal::Center,
+            truncation: Truncation::End,
+            center_on_window: false,
+        }
+    }
+}
+
+/// an area of the title bar that starts a window drag when pressed
 ///
-/// It can be useful if you want to fill some space with nothing.
-#[derive(Debug)]
-pub struct DragWindow<Message> {
+/// the title is either a text or any element, presses the element does not
+/// capture still start the window drag
+pub struct DragWindow<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     width: Length,
     height: Length,
-    message: Message,
+    /// published when the window should be dragged, none for a plain title
+    message: Option<Message>,
     title: Option<String>,
+    title_style: TitleStyle,
+    content: Option<Element<'a, Message, Theme, Renderer>>,
+    threshold: Option<f32>,
+    gestures: Gestures<Message>,
 }
 
-impl<Message> DragWindow<Message> {
+impl<'a, Message, Theme, Renderer> DragWindow<'a, Message, Theme, Renderer> {
     /// Creates an amount of empty [`Space`] with the given width and height.
     pub fn new(width: impl Into<Length>, height: impl Into<Length>, message: Message) -> Self {
         DragWindow {
             width: width.into(),
             height: height.into(),
-            message,
+            message: Some(message),
             title: None,
+            title_style: TitleStyle::default(),
+            content: None,
+            threshold: None,
+            gestures: Gestures::default(),
         }
     }
 
@@ -30,8 +131,12 @@ impl<Message> DragWindow<Message> {
         DragWindow {
             width: width.into(),
             height: Length::Fill,
-            message,
+            message: Some(message),
             title: None,
+            title_style: TitleStyle::default(),
+            content: None,
+            threshold: None,
+            gestures: Gestures::default(),
         }
     }
 
@@ -40,8 +145,27 @@ impl<Message> DragWindow<Message> {
         DragWindow {
             width: Length::Fill,
             height: height.into(),
-            message,
+            message: Some(message),
+            title: None,
+            title_style: TitleStyle::default(),
+            content: None,
+            threshold: None,
+            gestures: Gestures::default(),
+        }
+    }
+
+    /// a title that never drags the window, for titles inside the window like
+    /// pane title bars, its gestures still publish their messages
+    pub fn label(width: impl Into<Length>, height: impl Into<Length>) -> Self {
+        DragWindow {
+            width: width.into(),
+            height: height.into(),
+            message: None,
             title: None,
+            title_style: TitleStyle::default(),
+            content: None,
+            threshold: None,
+            gestures: Gestures::default(),
         }
     }
 
@@ -49,13 +173,239 @@ impl<Message> DragWindow<Message> {
         self.title = title;
         self
     }
+
+    pub fn title_style(mut self, title_style: TitleStyle) -> Self {
+        self.title_style = title_style;
+        self
+    }
+
+    /// use an element as the title instead of the text title, it is placed by
+    /// the alignment of the [`TitleStyle`]
+    pub fn content(mut self, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// only drag the window once the pointer moved this far while pressed, so
+    /// clicks on the title do not grab the pointer
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// messages published for clicks and scrolling on the title
+    pub fn gestures(mut self, gestures: Gestures<Message>) -> Self {
+        self.gestures = gestures;
+        self
+    }
+}
+
+/// messages for the gestures on a [`DragWindow`], a double click publishes its
+/// message instead of dragging the window
+#[derive(Debug, Clone)]
+pub struct Gestures<Message> {
+    pub double_click: Option<Message>,
+    pub middle_click: Option<Message>,
+    pub right_click: Option<Message>,
+    pub scroll_up: Option<Message>,
+    pub scroll_down: Option<Message>,
+}
+
+impl<Message> Gestures<Message> {
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.double_click = Some(message);
+        self
+    }
+
+    pub fn on_middle_click(mut self, message: Message) -> Self {
+        self.middle_click = Some(message);
+        self
+    }
+
+    pub fn on_right_click(mut self, message: Message) -> Self {
+        self.right_click = Some(message);
+        self
+    }
+
+    pub fn on_scroll(mut self, up: Option<Message>, down: Option<Message>) -> Self {
+        self.scroll_up = up;
+        self.scroll_down = down;
+        self
+    }
+}
+
+impl<Message> Default for Gestures<Message> {
+    fn default() -> Self {
+        Self {
+            double_click: None,
+            middle_click: None,
+            right_click: None,
+            scroll_up: None,
+            scroll_down: None,
+        }
+    }
+}
+
+impl<Message: Clone> Gestures<Message> {
+    /// the message of the gesture the event completes, remembers left clicks
+    /// to find double clicks
+    pub(crate) fn message(
+        &self,
+        event: &Event,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        press: &mut Press,
+    ) -> Option<Message> {
+        let position = cursor.position_over(bounds)?;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let click = mouse::Click::new(position, press.last_click);
+                press.last_click = Some(click);
+
+                match click.kind() {
+                    mouse::click::Kind::Double => self.double_click.clone(),
+                    _ => None,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) => {
+                self.middle_click.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                self.right_click.clone()
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
+                        *y
+                    }
+                };
+
+                if y > 0.0 {
+                    self.scroll_up.clone()
+                } else if y < 0.0 {
+                    self.scroll_down.clone()
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// the shaped title, only shaped again when the title, its font or the bounds change
+#[derive(Debug, Default)]
+struct State<P: Paragraph> {
+    paragraph: P,
+    title: String,
+    font: Option<Font>,
+    size: Pixels,
+    truncation: Truncation,
+    available: f32,
+    press: Press,
+}
+
+impl TitleStyle {
+    /// the font and size of the title with the renderer defaults filled in
+    fn resolve<Renderer>(&self, renderer: &Renderer) -> (Font, Pixels)
+    where
+        Renderer: iced::advanced::text::Renderer<Font = Font>,
+    {
+        let mut font = self.font.unwrap_or_else(|| renderer.default_font());
+        if let Some(weight) = self.weight {
+            font.weight = weight;
+        }
+
+        (font, self.size.unwrap_or_else(|| renderer.default_size()))
+    }
+
+    /// the font of the title for text widgets, none leaves the default font of
+    /// the renderer unless a weight is set
+    pub(crate) fn text_font(&self) -> Option<Font> {
+        match (self.font, self.weight) {
+            (font, Some(weight)) => Some(Font {
+                weight,
+                ..font.unwrap_or(Font::DEFAULT)
+            }),
+            (font, None) => font,
+        }
+    }
 }
 
-impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DragWindow<Message>
+fn title_text(content: &str, font: Font, size: Pixels) -> Text<'_, Font> {
+    Text {
+        content,
+        bounds: Size::INFINITY,
+        size,
+        line_height: LineHeight::default(),
+        font,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Center,
+        shaping: Shaping::Advanced,
+    }
+}
+
+/// shortens the title with an ellipsis until `measure` reports that it fits into `max_width`
+fn truncate(
+    title: &str,
+    truncation: Truncation,
+    max_width: f32,
+    measure: impl Fn(&str) -> f32,
+) -> String {
+    if truncation == Truncation::None || measure(title) <= max_width {
+        return title.to_owned();
+    }
+
+    let chars: Vec<char> = title.chars().collect();
+    let shorten = |keep: usize| -> String {
+        let (head, tail) = match truncation {
+            Truncation::Middle => (keep.div_ceil(2), keep / 2),
+            _ => (keep, 0),
+        };
+        let head: String = chars[..head].iter().collect();
+        let tail: String = chars[chars.len() - tail..].iter().collect();
+
+        format!("{}\u{2026}{}", head.trim_end(), tail.trim_start())
+    };
+
+    // the most characters that still fit
+    let (mut low, mut high) = (0, chars.len());
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+
+        if measure(&shorten(mid)) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    shorten(low)
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragWindow<'a, Message, Theme, Renderer>
 where
     Message: Clone,
-    Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer,
+    Renderer: iced::advanced::Renderer + iced::advanced::text::Renderer<Font = Font>,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(self.content.as_slice());
+    }
+
     fn size(&self) -> iced::Size<Length> {
         iced::Size {
             width: self.width,
@@ -65,93 +415,523 @@ where
 
     fn layout(
         &self,
-        _tree: &mut Tree,
-        _renderer: &Renderer,
+        tree: &mut Tree,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        if let Some(content) = &self.content {
+            let node = content.as_widget().layout(
+                &mut tree.children[0],
+                renderer,
+                &layout::Limits::new(Size::ZERO, size),
+            );
+            let content_size = node.size();
+
+            let x = match self.title_style.alignment {
+                alignment::Horizontal::Left => TITLE_PADDING,
+                alignment::Horizontal::Center => (size.width - content_size.width) / 2.0,
+                alignment::Horizontal::Right => size.width - content_size.width - TITLE_PADDING,
+            };
+            let y = (size.height - content_size.height) / 2.0;
+
+            return layout::Node::with_children(
+                size,
+                vec![node.move_to(Point::new(x.max(0.0), y))],
+            );
+        }
 
-        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+        if let Some(title) = &self.title {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            let (font, text_size) = self.title_style.resolve(renderer);
+            let truncation = self.title_style.truncation;
+            let available = (size.width - TITLE_PADDING * 2.0).max(0.0);
+
+            if state.title != *title
+                || state.font != Some(font)
+                || state.size != text_size
+                || state.truncation != truncation
+                || state.available != available
+            {
+                let measure = |content: &str| {
+                    Renderer::Paragraph::with_text(title_text(content, font, text_size))
+                        .min_bounds()
+                        .width
+                };
+                let content = truncate(title, truncation, available, measure);
+
+                *state = State {
+                    paragraph: Renderer::Paragraph::with_text(title_text(
+                        &content, font, text_size,
+                    )),
+                    title: title.clone(),
+                    font: Some(font),
+                    size: text_size,
+                    truncation,
+                    available,
+                    press: state.press,
+                };
+            }
+        }
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(content) = &self.content {
+            content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        }
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: iced::Event,
         layout: layout::Layout<'_>,
         cursor: iced::advanced::mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn iced::advanced::Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced::advanced::Clipboard,
         shell: &mut iced::advanced::Shell<'_, Message>,
-        _viewport: &iced::Rectangle,
-    ) -> iced::advanced::graphics::core::event::Status {
-        match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor.is_over(layout.bounds()) {
-                    shell.publish(self.message.clone())
-                }
+        viewport: &iced::Rectangle,
+    ) -> event::Status {
+        tree.state
+            .downcast_mut::<State<Renderer::Paragraph>>()
+            .press
+            .reset(&event);
+
+        // the title element gets the event first, only presses it ignores drag the window
+        if let Some(content) = &mut self.content {
+            let status = content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return status;
             }
-            _ => {}
         }
-        iced::advanced::graphics::core::event::Status::Ignored
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        if let Some(message) =
+            self.gestures
+                .message(&event, cursor, layout.bounds(), &mut state.press)
+        {
+            state.press.origin = None;
+            shell.publish(message);
+            return event::Status::Captured;
+        }
+
+        if let Some(message) = &self.message {
+            if state
+                .press
+                .update(&event, cursor, layout.bounds(), &[], self.threshold)
+            {
+                shell.publish(message.clone())
+            }
+        }
+        event::Status::Ignored
     }
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
-        _theme: &Theme,
+        theme: &Theme,
         style: &iced::advanced::renderer::Style,
         layout: layout::Layout<'_>,
-        _cursor: iced::advanced::mouse::Cursor,
-        _viewport: &iced::Rectangle,
+        cursor: iced::advanced::mouse::Cursor,
+        viewport: &iced::Rectangle,
     ) {
-        if let Some(title) = &self.title {
+        if let Some(content) = &self.content {
+            content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        } else if self.title.is_some() {
+            let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
             let bounds = layout.bounds();
+            let title_style = &self.title_style;
+            let width = state.paragraph.min_bounds().width;
 
-            let horizontal_alignment = alignment::Horizontal::Center;
-            let vertical_alignment = alignment::Vertical::Center;
-
-            let x = match horizontal_alignment {
-                alignment::Horizontal::Left => bounds.x,
-                alignment::Horizontal::Center => bounds.center_x(),
-                alignment::Horizontal::Right => bounds.x + bounds.width,
-            };
+            let left = bounds.x + TITLE_PADDING;
+            let right = bounds.x + bounds.width - TITLE_PADDING;
+            let x = match title_style.alignment {
+                alignment::Horizontal::Left => left,
+                alignment::Horizontal::Right => right - width,
+                alignment::Horizontal::Center => {
+                    let centered = bounds.center_x() - width / 2.0;
+                    let on_window = viewport.center_x() - width / 2.0;
 
-            let y = match vertical_alignment {
-                alignment::Vertical::Top => bounds.y,
-                alignment::Vertical::Center => bounds.center_y(),
-                alignment::Vertical::Bottom => bounds.y + bounds.height,
+                    if title_style.center_on_window
+                        && on_window >= left
+                        && on_window + width <= right
+                    {
+                        on_window
+                    } else {
+                        centered
+                    }
+                }
             };
 
-            renderer.fill_text(
-                Text {
-                    content: title,
-                    bounds: Size::new(bounds.width, bounds.height),
-                    size: renderer.default_size(),
-
-                    line_height: Default::default(),
-                    shaping: iced::widget::text::Shaping::Basic,
-                    font: renderer.default_font(),
-                    horizontal_alignment,
-                    vertical_alignment,
-                },
-                Point::new(x, y),
-                style.text_color,
+            renderer.fill_paragraph(
+                &state.paragraph,
+                Point::new(x, bounds.center_y()),
+                title_style.color.unwrap_or(style.text_color),
                 bounds,
             );
         }
     }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: iced::advanced::mouse::Cursor,
+        viewport: &iced::Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_ref()
+            .map_or(mouse::Interaction::default(), |content| {
+                content.as_widget().mouse_interaction(
+                    &tree.children[0],
+                    layout.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_mut()?.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
 }
 
-impl<'a, Message, Theme, Renderer> From<DragWindow<Message>>
+impl<'a, Message, Theme, Renderer> From<DragWindow<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
     Message: 'a + Clone,
-    Renderer: 'a + iced::advanced::Renderer + iced::advanced::text::Renderer,
+    Theme: 'a,
+    Renderer: 'a + iced::advanced::Renderer + iced::advanced::text::Renderer<Font = Font>,
 {
-    fn from(space: DragWindow<Message>) -> Element<'a, Message, Theme, Renderer> {
+    fn from(
+        space: DragWindow<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
         Element::new(space)
     }
 }
+
+/// whether the event is a press inside the bounds and outside of the excluded
+/// rectangles, which are relative to the bounds
+fn starts_drag(
+    event: &Event,
+    cursor: mouse::Cursor,
+    bounds: Rectangle,
+    exclude: &[Rectangle],
+) -> bool {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            cursor.position_over(bounds).is_some_and(|position| {
+                let position = position - Vector::new(bounds.x, bounds.y);
+
+                !exclude.iter().any(|area| area.contains(position))
+            })
+        }
+        _ => false,
+    }
+}
+
+/// a press waiting for the pointer to move past the drag threshold
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Press {
+    pub(crate) origin: Option<Point>,
+    last_click: Option<mouse::Click>,
+}
+
+impl Press {
+    /// whether the event starts the window drag, without a threshold every
+    /// press does
+    pub(crate) fn update(
+        &mut self,
+        event: &Event,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+        exclude: &[Rectangle],
+        threshold: Option<f32>,
+    ) -> bool {
+        let Some(threshold) = threshold else {
+            return starts_drag(event, cursor, bounds, exclude);
+        };
+
+        if starts_drag(event, cursor, bounds, exclude) {
+            self.origin = cursor.position();
+            return false;
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. }) = event
+        {
+            if self
+                .origin
+                .is_some_and(|origin| origin.distance(*position) >= threshold)
+            {
+                self.origin = None;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// forgets the press when the button is released or the cursor leaves the
+    /// window, also when a child captures the event
+    pub(crate) fn reset(&mut self, event: &Event) {
+        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Mouse(mouse::Event::CursorLeft)
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) = event
+        {
+            self.origin = None;
+        }
+    }
+}
+
+/// wraps any element so presses its widgets do not capture drag the window
+pub fn drag_area<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    message: Message,
+) -> DragArea<'a, Message, Theme, Renderer> {
+    DragArea::new(content, message)
+}
+
+/// a container that publishes the drag message for presses its content ignores,
+/// to make a custom toolbar or sidebar move the window like the title bar
+pub struct DragArea<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    message: Message,
+    exclude: Vec<Rectangle>,
+    threshold: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> DragArea<'a, Message, Theme, Renderer> {
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        message: Message,
+    ) -> Self {
+        DragArea {
+            content: content.into(),
+            message,
+            exclude: Vec::new(),
+            threshold: None,
+        }
+    }
+
+    /// a rectangle relative to the area where presses never drag the window
+    pub fn exclude(mut self, area: Rectangle) -> Self {
+        self.exclude.push(area);
+        self
+    }
+
+    /// only drag the window once the pointer moved this far while pressed
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DragArea<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Press>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Press::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(node.size(), vec![node])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: iced::Event,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn iced::advanced::Clipboard,
+        shell: &mut iced::advanced::Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        tree.state.downcast_mut::<Press>().reset(&event);
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if tree.state.downcast_mut::<Press>().update(
+            &event,
+            cursor,
+            layout.bounds(),
+            &self.exclude,
+            self.threshold,
+        ) {
+            shell.publish(self.message.clone())
+        }
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &iced::advanced::renderer::Style,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: layout::Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: layout::Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DragArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + iced::advanced::Renderer,
+{
+    fn from(area: DragArea<'a, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(area)
+    }
+}
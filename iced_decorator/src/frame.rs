@@ -0,0 +1,84 @@
+use iced::{color, Color, Rectangle, Shadow, Vector};
+
+/// rounded frame with a border and a drop shadow drawn around the window
+///
+/// the shadow is drawn into a transparent margin around the frame, so the window
+/// has to be created with [`transparent`](iced::window::Settings::transparent) on.
+/// corners, border and shadow are dropped while the window is maximized.
+///
+/// iced can only clip to rectangles, the content is clipped to the inside of the
+/// border and the close button is rounded to match the top corner
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame {
+    /// radius of the window corners
+    pub radius: f32,
+    /// width of the border around the window
+    pub border_width: f32,
+    /// color of the border around the window
+    pub border_color: Color,
+    /// background of the window inside the frame
+    pub background: Color,
+    /// shadow drawn into the margin around the frame
+    pub shadow: Shadow,
+    /// transparent space between the window edges and the frame
+    pub margin: f32,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn border(mut self, width: f32, color: Color) -> Self {
+        self.border_width = width;
+        self.border_color = color;
+        self
+    }
+
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Self {
+            radius: 8.0,
+            border_width: 1.0,
+            border_color: color!(0, 0, 0, 0.3),
+            background: Color::WHITE,
+            shadow: Shadow {
+                color: color!(0, 0, 0, 0.4),
+                offset: Vector::new(0.0, 2.0),
+                blur_radius: 10.0,
+            },
+            margin: 12.0,
+        }
+    }
+}
+
+/// shrinks the bounds by the given amount on every side
+pub(crate) fn shrink(bounds: Rectangle, amount: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x + amount,
+        y: bounds.y + amount,
+        width: (bounds.width - amount * 2.0).max(0.0),
+        height: (bounds.height - amount * 2.0).max(0.0),
+    }
+}
@@ -0,0 +1,232 @@
+use iced::{
+    advanced::{layout, mouse, renderer, widget::Tree, Layout, Widget},
+    Border, Color, Element, Length, Point, Rectangle, Size,
+};
+
+use crate::PanelTheme;
+
+/// the glyphs of the caption buttons and a few app buttons
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Glyph {
+    Minimize,
+    Maximize,
+    Restore,
+    Close,
+    /// keep the window on top, shown while it is not pinned
+    Pin,
+    /// stop keeping the window on top, shown while it is pinned
+    Unpin,
+    /// add an item, like the new tab button
+    Add,
+    /// enter or leave fullscreen, for app buttons
+    Fullscreen,
+    /// open a menu, for app buttons
+    Menu,
+}
+
+pub fn icon(glyph: Glyph) -> Icon {
+    Icon::new(glyph)
+}
+
+/// a caption [`Glyph`] drawn with quads in the icon color of the [`PanelTheme`],
+/// used for the panel buttons when the `svg` feature is off
+///
+/// the glyphs are designed on a 16 unit grid, scaled to the icon size and
+/// centered horizontally like an svg
+#[derive(Debug, Clone, Copy)]
+pub struct Icon {
+    glyph: Glyph,
+    size: f32,
+}
+
+impl Icon {
+    pub fn new(glyph: Glyph) -> Self {
+        Icon { glyph, size: 16.0 }
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, PanelTheme, Renderer> for Icon
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(Length::Fill, self.size, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &PanelTheme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let side = bounds.width.min(bounds.height);
+        let unit = side / 16.0;
+        let stroke = unit.max(1.0).round();
+        let origin = Point::new(
+            bounds.center_x() - side / 2.0,
+            bounds.center_y() - side / 2.0,
+        );
+        let color = theme.svg;
+
+        // a rectangle on the 16 unit grid
+        let grid = |x: f32, y: f32, width: f32, height: f32| Rectangle {
+            x: origin.x + x * unit,
+            y: origin.y + y * unit,
+            width: width * unit,
+            height: height * unit,
+        };
+        let horizontal = |x: f32, y: f32, length: f32| Rectangle {
+            height: stroke,
+            ..grid(x, y, length, 0.0)
+        };
+        let vertical = |x: f32, y: f32, length: f32| Rectangle {
+            width: stroke,
+            ..grid(x, y, 0.0, length)
+        };
+
+        match self.glyph {
+            Glyph::Minimize => {
+                fill(renderer, horizontal(3.0, 8.0, 10.0), color);
+            }
+            Glyph::Maximize => {
+                outline(renderer, grid(3.0, 3.0, 10.0, 10.0), stroke, color);
+            }
+            Glyph::Restore => {
+                outline(renderer, grid(3.0, 5.0, 8.0, 8.0), stroke, color);
+                fill(renderer, horizontal(5.0, 3.0, 8.0), color);
+                fill(renderer, vertical(13.0 - stroke / unit, 3.0, 8.0), color);
+                fill(renderer, vertical(5.0, 3.0, 2.0), color);
+                fill(renderer, horizontal(11.0, 11.0 - stroke / unit, 2.0), color);
+            }
+            Glyph::Pin | Glyph::Unpin => {
+                let head = grid(5.0, 2.0, 6.0, 6.0);
+                if self.glyph == Glyph::Unpin {
+                    fill(renderer, head, color);
+                } else {
+                    outline(renderer, head, stroke, color);
+                }
+                fill(renderer, horizontal(3.0, 8.0, 10.0), color);
+                fill(
+                    renderer,
+                    vertical(8.0 - stroke / unit / 2.0, 8.0, 6.0),
+                    color,
+                );
+            }
+            Glyph::Add => {
+                let center = 8.0 - stroke / unit / 2.0;
+
+                fill(renderer, horizontal(3.0, center, 10.0), color);
+                fill(renderer, vertical(center, 3.0, 10.0), color);
+            }
+            Glyph::Fullscreen => {
+                let end = 13.0 - stroke / unit;
+
+                // a bracket in each corner
+                for (x, y) in [(3.0, 3.0), (9.0, 3.0), (3.0, end), (9.0, end)] {
+                    fill(renderer, horizontal(x, y, 4.0), color);
+                }
+                for (x, y) in [(3.0, 3.0), (end, 3.0), (3.0, 9.0), (end, 9.0)] {
+                    fill(renderer, vertical(x, y, 4.0), color);
+                }
+            }
+            Glyph::Menu => {
+                let line = stroke / unit;
+
+                for y in [4.0, 8.0 - line / 2.0, 12.0 - line] {
+                    fill(renderer, horizontal(2.0, y, 12.0), color);
+                }
+            }
+            Glyph::Close => {
+                // diagonals are drawn as a staircase of squares
+                let length = 10.0 * unit;
+                let steps = (length / stroke).ceil().max(1.0);
+                let step = length / steps;
+
+                for i in 0..steps as usize {
+                    let offset = i as f32 * step;
+                    let size = Size::new(step.max(stroke), step.max(stroke));
+                    let start = grid(3.0, 3.0, 0.0, 0.0);
+
+                    fill(
+                        renderer,
+                        Rectangle::new(Point::new(start.x + offset, start.y + offset), size),
+                        color,
+                    );
+                    fill(
+                        renderer,
+                        Rectangle::new(
+                            Point::new(start.x + length - offset - size.width, start.y + offset),
+                            size,
+                        ),
+                        color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn fill<Renderer: iced::advanced::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..Default::default()
+        },
+        color,
+    );
+}
+
+fn outline<Renderer: iced::advanced::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    width: f32,
+    color: Color,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                color,
+                width,
+                radius: 0.0.into(),
+            },
+            ..Default::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
+
+impl<'a, Message, Renderer> From<Icon> for Element<'a, Message, PanelTheme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn from(icon: Icon) -> Self {
+        Element::new(icon)
+    }
+}
@@ -1,32 +1,51 @@
+use iced::{
+    advanced::widget::{self, operation::Outcome, Operation},
+    window::Id,
+    Point, Rectangle, Size,
+};
 
-use iced::{advanced::widget::Operation, Point, Rectangle, Size};
-use lazy_static::lazy_static;
+use crate::resizer::{ResizeEvent, ResizeState};
+use crate::window::WindowEvents;
+use crate::WindowHandler;
 
-use crate::resizer::ResizeState;
-
-lazy_static! {
-    pub static ref RESIZER_ID: iced::advanced::widget::Id = iced::advanced::widget::Id::new("rsizer");
+/// the id of the resizer of the window, the widget operations run on every
+/// window so each resizer needs its own
+pub fn resizer_id(window: Id) -> widget::Id {
+    widget::Id::new(format!("rsizer-{window:?}"))
 }
+
 pub struct SetState {
     id: iced::advanced::widget::Id,
     position: Option<Point>,
     size: Option<Size>,
+    maximized: Option<bool>,
 }
 
 impl SetState {
-    pub fn with_position(position: Point) -> Self {
+    pub fn with_position(window: Id, position: Point) -> Self {
         SetState {
-            id: RESIZER_ID.clone(),
+            id: resizer_id(window),
             position: Some(position),
             size: None,
+            maximized: None,
         }
     }
 
-    pub fn with_size(size: Size) -> Self {
+    pub fn with_size(window: Id, size: Size) -> Self {
         SetState {
-            id: RESIZER_ID.clone(),
+            id: resizer_id(window),
             position: None,
             size: Some(size),
+            maximized: None,
+        }
+    }
+
+    pub fn with_maximized(window: Id, maximized: bool) -> Self {
+        SetState {
+            id: resizer_id(window),
+            position: None,
+            size: None,
+            maximized: Some(maximized),
         }
     }
 }
@@ -50,7 +69,69 @@ impl<T> Operation<T> for SetState {
                 if let Some(size) = self.size {
                     state.window_size = size;
                 }
+                if let Some(maximized) = self.maximized {
+                    // a shaded window stays shaded while it is maximized, it
+                    // returns to its title bar when it is unmaximized and the
+                    // next toggle restores its full height
+                    state.maximized = maximized;
+                }
+            }
+        }
+    }
+}
+
+/// shades the window to its title bar or restores the height it had before
+pub struct ToggleShade {
+    window: Id,
+    id: iced::advanced::widget::Id,
+    size: Option<Size>,
+}
+
+impl ToggleShade {
+    pub fn new(window: Id) -> Self {
+        ToggleShade {
+            window,
+            id: resizer_id(window),
+            size: None,
+        }
+    }
+}
+
+impl<T: WindowHandler> Operation<T> for ToggleShade {
+    fn container(
+        &mut self,
+        _id: Option<&iced::advanced::widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn custom(&mut self, state: &mut dyn std::any::Any, id: Option<&iced::advanced::widget::Id>) {
+        if Some(&self.id) == id {
+            if let Some(state) = state.downcast_mut::<ResizeState>() {
+                if state.maximized {
+                    return;
+                }
+
+                let height = match state.shaded.take() {
+                    Some(height) => height,
+                    None => {
+                        state.shaded = Some(state.window_size.height);
+                        state.shade_height
+                    }
+                };
+                self.size = Some(Size::new(state.window_size.width, height));
             }
         }
     }
+
+    fn finish(&self) -> Outcome<T> {
+        match self.size {
+            Some(size) => Outcome::Some(T::event_handler(
+                WindowEvents::ResizeEvent(ResizeEvent::ResizeXY(size)).for_window(self.window),
+            )),
+            None => Outcome::None,
+        }
+    }
 }
@@ -1,5 +1,19 @@
 use crate::window::WindowEvents;
 
+/// renderer that can draw the panel icons, needs svg support with the `svg` feature
+#[cfg(feature = "svg")]
+pub trait IconRenderer: iced::advanced::svg::Renderer {}
+
+#[cfg(feature = "svg")]
+impl<T: iced::advanced::svg::Renderer> IconRenderer for T {}
+
+/// renderer that can draw the panel icons, needs svg support with the `svg` feature
+#[cfg(not(feature = "svg"))]
+pub trait IconRenderer: iced::advanced::Renderer {}
+
+#[cfg(not(feature = "svg"))]
+impl<T: iced::advanced::Renderer> IconRenderer for T {}
+
 pub trait WindowHandler {
     fn event_handler(event: WindowEvents) -> Self;
 }
@@ -1,9 +1,22 @@
+pub mod dock;
 pub mod drag_window;
+pub mod frame;
+pub mod glyphs;
+pub mod mdi;
+pub mod pane;
+pub mod panel_button;
+pub mod resizable;
 mod resizer;
+#[cfg(feature = "svg")]
 pub mod svgs;
-pub mod window;
+pub mod tabs;
+pub mod tear_off;
 mod theming;
+pub mod window;
 
+pub use resizer::ResizeArea;
+pub use theming::focus::FocusThemer;
+pub use theming::panel_buttons::Button as PanelButton;
 pub use theming::panel_buttons::Theme as PanelTheme;
 pub(crate) mod helpers;
 
@@ -0,0 +1,1119 @@
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, touch,
+    widget::{button, column, text, Row},
+    Border, Color, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use crate::drag_window::{DragWindow, Gestures, TitleStyle};
+use crate::frame::Frame;
+use crate::glyphs::Glyph;
+use crate::panel_button::PanelButtons;
+use crate::resizable::Edges;
+use crate::resizer::{hit_edge, Dragging};
+use crate::theming::focus::FocusThemer;
+use crate::window::TitleBarMetrics;
+use crate::{IconRenderer, PanelButton, PanelTheme};
+
+/// width of the resize band along the inside of the sub window edges
+const RESIZE_BORDER: f32 = 5.0;
+/// smallest size a sub window can be resized to
+const MIN_SIZE: Size = Size::new(120.0, 60.0);
+/// offset between the sub windows when they are cascaded
+const CASCADE_OFFSET: f32 = 30.0;
+
+/// the geometry messages of the sub windows, pass them to [`Desktop::update`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MdiEvent {
+    /// the area of the container changed
+    Area(Size),
+    /// raise the sub window to the top
+    Focus(usize),
+    Move(usize, Point),
+    Resize(usize, Rectangle),
+    Minimize(usize),
+    Restore(usize),
+    ToggleMaximize(usize),
+    Close(usize),
+}
+
+/// a sub window of the [`Desktop`], the bounds are relative to the container
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubWindowState {
+    pub id: usize,
+    pub bounds: Rectangle,
+    pub minimized: bool,
+    pub maximized: bool,
+}
+
+/// the sub windows of an [`Mdi`] container from back to front, kept by the app
+#[derive(Debug, Clone, Default)]
+pub struct Desktop {
+    windows: Vec<SubWindowState>,
+    next_id: usize,
+    area: Size,
+}
+
+impl Desktop {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// opens a sub window on top of the others and returns its id
+    pub fn open(&mut self, bounds: Rectangle) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.windows.push(SubWindowState {
+            id,
+            bounds,
+            minimized: false,
+            maximized: false,
+        });
+        id
+    }
+
+    pub fn close(&mut self, id: usize) {
+        self.windows.retain(|window| window.id != id);
+    }
+
+    /// raises the sub window to the top
+    pub fn focus(&mut self, id: usize) {
+        if let Some(index) = self.windows.iter().position(|window| window.id == id) {
+            let window = self.windows.remove(index);
+            self.windows.push(window);
+        }
+    }
+
+    /// the top sub window that is not minimized
+    pub fn focused(&self) -> Option<usize> {
+        self.windows
+            .iter()
+            .rev()
+            .find(|window| !window.minimized)
+            .map(|window| window.id)
+    }
+
+    pub fn get(&self, id: usize) -> Option<&SubWindowState> {
+        self.windows.iter().find(|window| window.id == id)
+    }
+
+    /// the sub windows from back to front
+    pub fn windows(&self) -> impl Iterator<Item = &SubWindowState> {
+        self.windows.iter()
+    }
+
+    /// size of the container the sub windows are placed in, zero until the
+    /// [`Mdi`] publishes its first [`MdiEvent::Area`]
+    pub fn area(&self) -> Size {
+        self.area
+    }
+
+    pub fn update(&mut self, event: MdiEvent) {
+        if let MdiEvent::Area(area) = event {
+            self.area = area;
+            return;
+        }
+        if let MdiEvent::Close(id) = event {
+            self.close(id);
+            return;
+        }
+
+        let (MdiEvent::Focus(id)
+        | MdiEvent::Move(id, _)
+        | MdiEvent::Resize(id, _)
+        | MdiEvent::Minimize(id)
+        | MdiEvent::Restore(id)
+        | MdiEvent::ToggleMaximize(id)) = event
+        else {
+            return;
+        };
+        let Some(window) = self.windows.iter_mut().find(|window| window.id == id) else {
+            return;
+        };
+
+        match event {
+            MdiEvent::Move(_, position) => {
+                window.bounds.x = position.x;
+                window.bounds.y = position.y;
+            }
+            MdiEvent::Resize(_, bounds) => window.bounds = bounds,
+            MdiEvent::Minimize(_) => window.minimized = true,
+            MdiEvent::Restore(_) => window.minimized = false,
+            MdiEvent::ToggleMaximize(_) => window.maximized = !window.maximized,
+            _ => {}
+        }
+
+        if !matches!(event, MdiEvent::Minimize(_)) {
+            self.focus(id);
+        }
+    }
+
+    /// stacks the open sub windows diagonally from the top left corner
+    ///
+    /// the size comes from the [`area`](Desktop::area), which stays zero until
+    /// the [`Mdi`] reports it with [`MdiEvent::Area`] on its first event
+    pub fn cascade(&mut self) {
+        let size = Size::new(self.area.width * 0.6, self.area.height * 0.6);
+
+        for (i, window) in self
+            .windows
+            .iter_mut()
+            .filter(|window| !window.minimized)
+            .enumerate()
+        {
+            let offset = CASCADE_OFFSET * i as f32;
+
+            window.maximized = false;
+            window.bounds = Rectangle::new(Point::new(offset, offset), size);
+        }
+    }
+
+    /// places the open sub windows side by side in a grid filling the
+    /// [`area`](Desktop::area), like [`cascade`](Desktop::cascade) it needs the
+    /// area reported first
+    pub fn tile(&mut self) {
+        let count = self
+            .windows
+            .iter()
+            .filter(|window| !window.minimized)
+            .count();
+        if count == 0 {
+            return;
+        }
+
+        let columns = (count as f32).sqrt().ceil() as usize;
+        let rows = count.div_ceil(columns);
+        let size = Size::new(
+            self.area.width / columns as f32,
+            self.area.height / rows as f32,
+        );
+
+        for (i, window) in self
+            .windows
+            .iter_mut()
+            .filter(|window| !window.minimized)
+            .enumerate()
+        {
+            let (column, row) = (i % columns, i / columns);
+
+            window.maximized = false;
+            window.bounds = Rectangle::new(
+                Point::new(column as f32 * size.width, row as f32 * size.height),
+                size,
+            );
+        }
+    }
+}
+
+/// the title and content of a sub window
+pub struct SubWindow<'a, Message, Theme, Renderer> {
+    title: String,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> SubWindow<'a, Message, Theme, Renderer> {
+    pub fn new(
+        title: impl Into<String>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        SubWindow {
+            title: title.into(),
+            content: content.into(),
+        }
+    }
+}
+
+pub fn mdi<'a, Message, Theme, Renderer>(
+    desktop: &Desktop,
+    on_event: impl 'a + Fn(MdiEvent) -> Message,
+    view: impl Fn(&SubWindowState) -> SubWindow<'a, Message, Theme, Renderer>,
+) -> Mdi<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + IconRenderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a,
+{
+    Mdi::new(desktop, on_event, view)
+}
+
+/// floating sub windows inside the window, each with a title bar, caption
+/// buttons and resizable edges
+///
+/// the container only publishes [`MdiEvent`]s, the app applies them to its
+/// [`Desktop`] which decides the geometry and stacking of the sub windows.
+/// minimized sub windows are shown in a bar along the bottom
+pub struct Mdi<'a, Message, Theme, Renderer> {
+    /// sub windows ordered by id, so their widget state follows them when
+    /// they are raised
+    windows: Vec<SubWindowState>,
+    /// indices into `windows` from back to front
+    stacking: Vec<usize>,
+    /// one child per sub window and the bar with the minimized ones last
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_event: Box<dyn Fn(MdiEvent) -> Message + 'a>,
+    area: Size,
+    frame: Frame,
+    title_bar_height: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Mdi<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + IconRenderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a,
+{
+    pub fn new(
+        desktop: &Desktop,
+        on_event: impl 'a + Fn(MdiEvent) -> Message,
+        view: impl Fn(&SubWindowState) -> SubWindow<'a, Message, Theme, Renderer>,
+    ) -> Self {
+        Self::with_style(desktop, on_event, view, MdiStyle::default())
+    }
+
+    /// like [`Mdi::new`] with the given look of the sub windows
+    pub fn with_style(
+        desktop: &Desktop,
+        on_event: impl 'a + Fn(MdiEvent) -> Message,
+        view: impl Fn(&SubWindowState) -> SubWindow<'a, Message, Theme, Renderer>,
+        style: MdiStyle,
+    ) -> Self {
+        let mut windows: Vec<SubWindowState> = desktop.windows().copied().collect();
+        windows.sort_by_key(|window| window.id);
+
+        let stacking = desktop
+            .windows()
+            .filter(|window| !window.minimized)
+            .filter_map(|stacked| windows.iter().position(|window| window.id == stacked.id))
+            .collect();
+        let focused = desktop.focused();
+        let inactive = style
+            .inactive_panel_theme
+            .unwrap_or_else(|| style.panel_theme.inactive());
+
+        let mut children: Vec<Element<'a, Message, Theme, Renderer>> =
+            Vec::with_capacity(windows.len() + 1);
+        let mut minimized: Vec<Element<'a, Message, PanelTheme, Renderer>> = Vec::new();
+
+        for window in &windows {
+            let SubWindow { title, content } = view(window);
+            let id = window.id;
+
+            if window.minimized {
+                minimized.push(
+                    button(text(title).size(12))
+                        .style(PanelButton::Custom)
+                        .padding([2, 8])
+                        .on_press(on_event(MdiEvent::Restore(id)))
+                        .into(),
+                );
+                children.push(content);
+                continue;
+            }
+
+            let active = if focused == Some(id) {
+                style.panel_theme
+            } else {
+                inactive
+            };
+            let height = style.panel_buttons.metrics.height;
+            let maximize = if window.maximized {
+                Glyph::Restore
+            } else {
+                Glyph::Maximize
+            };
+
+            let title = DragWindow::label(Length::Fill, height)
+                .title_style(style.title_style)
+                .set_title(Some(title))
+                .gestures(
+                    Gestures::default().on_double_click(on_event(MdiEvent::ToggleMaximize(id))),
+                );
+            let buttons: Vec<Element<'a, Message, PanelTheme, Renderer>> = vec![
+                style
+                    .panel_buttons
+                    .button(Glyph::Minimize)
+                    .on_press(on_event(MdiEvent::Minimize(id)))
+                    .into(),
+                style
+                    .panel_buttons
+                    .button(maximize)
+                    .on_press(on_event(MdiEvent::ToggleMaximize(id)))
+                    .into(),
+                style
+                    .panel_buttons
+                    .button(Glyph::Close)
+                    .on_press(on_event(MdiEvent::Close(id)))
+                    .into(),
+            ];
+
+            let title_bar = Row::with_children(vec![
+                FocusThemer::new(active, inactive, title)
+                    .title_color(true)
+                    .into(),
+                FocusThemer::new(active, inactive, Row::with_children(buttons)).into(),
+            ])
+            .height(height);
+
+            children.push(column![title_bar, content].into());
+        }
+
+        let bar: Element<'a, Message, PanelTheme, Renderer> = if minimized.is_empty() {
+            Row::new().into()
+        } else {
+            Row::with_children(minimized).spacing(4).padding(4).into()
+        };
+        children.push(FocusThemer::new(style.panel_theme, inactive, bar).into());
+
+        Mdi {
+            windows,
+            stacking,
+            children,
+            on_event: Box::new(on_event),
+            area: desktop.area(),
+            frame: style.frame,
+            title_bar_height: style.panel_buttons.metrics.height,
+        }
+    }
+}
+
+/// the look of the sub windows of an [`Mdi`] container
+#[derive(Debug, Clone)]
+pub struct MdiStyle {
+    pub frame: Frame,
+    pub title_style: TitleStyle,
+    pub panel_theme: PanelTheme,
+    pub inactive_panel_theme: Option<PanelTheme>,
+    pub panel_buttons: PanelButtons,
+}
+
+impl Default for MdiStyle {
+    fn default() -> Self {
+        Self {
+            frame: Frame::default(),
+            title_style: TitleStyle::default(),
+            panel_theme: PanelTheme::default(),
+            inactive_panel_theme: None,
+            panel_buttons: PanelButtons::default().metrics(TitleBarMetrics::COMPACT),
+        }
+    }
+}
+
+/// the interaction with a sub window, which is found by its id on every event
+/// since the app can open and close sub windows while it lasts
+#[derive(Debug, Clone, Copy, Default)]
+enum Interaction {
+    #[default]
+    None,
+    /// moving the sub window of the width, grabbed at the offset from its corner
+    Moving { id: usize, grab: Vector, width: f32 },
+    /// resizing the sub window from the bounds it had on press
+    Resizing {
+        id: usize,
+        edge: Dragging,
+        origin: Rectangle,
+        press: Point,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    interaction: Interaction,
+}
+
+impl State {
+    /// the new geometry of the sub window the pointer moves or resizes, the
+    /// title bar stays inside the area and the edges do not leave its origin
+    fn interact(
+        &self,
+        event: &Event,
+        origin: Vector,
+        area: Size,
+        title_bar_height: f32,
+    ) -> Option<MdiEvent> {
+        let (Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. })) = event
+        else {
+            return None;
+        };
+
+        match self.interaction {
+            Interaction::Moving { id, grab, width } => Some(MdiEvent::Move(
+                id,
+                clamp_move(*position - origin - grab, width, area, title_bar_height),
+            )),
+            Interaction::Resizing {
+                id,
+                edge,
+                origin: from,
+                press,
+            } => Some(MdiEvent::Resize(id, resize(from, edge, *position - press))),
+            Interaction::None => None,
+        }
+    }
+}
+
+/// the position of a moved sub window, keeps its title bar inside the area
+fn clamp_move(position: Point, width: f32, area: Size, title_bar_height: f32) -> Point {
+    Point::new(
+        position.x.min(area.width - width).max(0.0),
+        position.y.min(area.height - title_bar_height).max(0.0),
+    )
+}
+
+/// the bounds of a sub window after dragging the edge by the delta, the left
+/// and top edges stop at the origin of the area and shrink the window instead
+fn resize(from: Rectangle, edge: Dragging, delta: Vector) -> Rectangle {
+    let mut resized = from;
+
+    match edge {
+        Dragging::HorizontalRight => {
+            resized.width = (from.width + delta.x).max(MIN_SIZE.width);
+        }
+        Dragging::VerticalBottom => {
+            resized.height = (from.height + delta.y).max(MIN_SIZE.height);
+        }
+        Dragging::HorizontalLeft => {
+            let right = from.x + from.width;
+
+            resized.x = (right - (from.width - delta.x).max(MIN_SIZE.width)).max(0.0);
+            resized.width = right - resized.x;
+        }
+        Dragging::VerticalTop => {
+            let bottom = from.y + from.height;
+
+            resized.y = (bottom - (from.height - delta.y).max(MIN_SIZE.height)).max(0.0);
+            resized.height = bottom - resized.y;
+        }
+        Dragging::None => {}
+    }
+
+    resized
+}
+
+impl<'a, Message, Theme, Renderer> Mdi<'a, Message, Theme, Renderer> {
+    /// the index of the sub window with the id
+    fn index(&self, id: usize) -> Option<usize> {
+        self.windows.iter().position(|window| window.id == id)
+    }
+
+    /// bounds of the sub window at the index relative to the container
+    fn window_bounds(&self, index: usize, area: Size, bar: f32) -> Rectangle {
+        let window = &self.windows[index];
+
+        if window.maximized {
+            Rectangle::new(Point::ORIGIN, Size::new(area.width, area.height - bar))
+        } else {
+            window.bounds
+        }
+    }
+
+    /// the top sub window under the position
+    fn hit(&self, layout: Layout<'_>, position: Point) -> Option<usize> {
+        let children: Vec<_> = layout.children().collect();
+
+        self.stacking
+            .iter()
+            .rev()
+            .copied()
+            .find(|&index| children[index].bounds().contains(position))
+    }
+
+    /// the edge of the sub window at the index under the position
+    fn edge(&self, layout: Layout<'_>, index: usize, position: Point) -> Dragging {
+        if self.windows[index].maximized {
+            return Dragging::None;
+        }
+
+        let bounds = layout.children().nth(index).unwrap().bounds();
+        hit_edge(bounds, RESIZE_BORDER, position, Edges::ALL)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Mdi<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let area = limits.max();
+        let (bar_tree, window_trees) = tree.children.split_last_mut().unwrap();
+        let (bar, windows) = self.children.split_last().unwrap();
+
+        let bar =
+            bar.as_widget()
+                .layout(bar_tree, renderer, &layout::Limits::new(Size::ZERO, area));
+        let bar_height = bar.size().height;
+
+        let mut nodes: Vec<layout::Node> = windows
+            .iter()
+            .zip(window_trees)
+            .enumerate()
+            .map(|(index, (window, tree))| {
+                let bounds = self.window_bounds(index, area, bar_height);
+                let size = if self.windows[index].minimized {
+                    Size::ZERO
+                } else {
+                    bounds.size()
+                };
+
+                window
+                    .as_widget()
+                    .layout(tree, renderer, &layout::Limits::new(size, size))
+                    .move_to(bounds.position())
+            })
+            .collect();
+        nodes.push(bar.move_to(Point::new(0.0, area.height - bar_height)));
+
+        layout::Node::with_children(area, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        if bounds.size() != self.area {
+            self.area = bounds.size();
+            shell.publish((self.on_event)(MdiEvent::Area(self.area)));
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let origin = Vector::new(bounds.x, bounds.y);
+
+        // the sub window went away while it was moved or resized
+        if let Interaction::Moving { id, .. } | Interaction::Resizing { id, .. } = state.interaction
+        {
+            if self.index(id).is_none() {
+                state.interaction = Interaction::None;
+            }
+        }
+
+        // moves stay above the bar of the minimized sub windows
+        let bar = layout.children().last().unwrap().bounds().height;
+        let area = Size::new(self.area.width, self.area.height - bar);
+
+        if let Some(message) = state.interact(&event, origin, area, self.title_bar_height) {
+            shell.publish((self.on_event)(message));
+            return event::Status::Captured;
+        }
+
+        if let (
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }),
+            Interaction::Moving { .. } | Interaction::Resizing { .. },
+        ) = (&event, state.interaction)
+        {
+            state.interaction = Interaction::None;
+            return event::Status::Captured;
+        }
+
+        let bar = self.children.len() - 1;
+        let status = self.children[bar].as_widget_mut().on_event(
+            &mut tree.children[bar],
+            event.clone(),
+            layout.children().nth(bar).unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let Some(position) = cursor.position() else {
+            return event::Status::Ignored;
+        };
+        let hit = self.hit(layout, position);
+        let pressed = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+
+        if let (Some(index), true) = (hit, pressed) {
+            if self.stacking.last() != Some(&index) {
+                shell.publish((self.on_event)(MdiEvent::Focus(self.windows[index].id)));
+            }
+
+            let edge = self.edge(layout, index, position);
+            if edge != Dragging::None {
+                let window = layout.children().nth(index).unwrap().bounds();
+
+                tree.state.downcast_mut::<State>().interaction = Interaction::Resizing {
+                    id: self.windows[index].id,
+                    edge,
+                    origin: Rectangle::new(window.position() - origin, window.size()),
+                    press: position,
+                };
+                return event::Status::Captured;
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+        let mut children: Vec<_> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(Some)
+            .collect();
+
+        // front to back, only the top sub window under the cursor sees it
+        for &index in self.stacking.iter().rev() {
+            let Some(((child, state), layout)) = children[index].take() else {
+                continue;
+            };
+            let cursor = if hit == Some(index) {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+
+            status = status.merge(child.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ));
+        }
+
+        // presses on the title bar the title bar ignores move the sub window
+        if let (Some(index), true, event::Status::Ignored) = (hit, pressed, status) {
+            let window = layout.children().nth(index).unwrap().bounds();
+
+            if !self.windows[index].maximized && position.y < window.y + self.title_bar_height {
+                tree.state.downcast_mut::<State>().interaction = Interaction::Moving {
+                    id: self.windows[index].id,
+                    grab: position - window.position(),
+                    width: window.width,
+                };
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let children: Vec<_> = layout.children().collect();
+        let hit = cursor
+            .position()
+            .and_then(|position| self.hit(layout, position));
+        let frame = self.frame;
+
+        for &index in &self.stacking {
+            let bounds = children[index].bounds();
+            let cursor = if hit == Some(index) {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+            let radius = if self.windows[index].maximized {
+                0.0
+            } else {
+                frame.radius
+            };
+
+            renderer.with_layer(*viewport, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            radius: radius.into(),
+                            ..Border::default()
+                        },
+                        shadow: frame.shadow,
+                    },
+                    frame.background,
+                );
+
+                renderer.with_layer(bounds, |renderer| {
+                    self.children[index].as_widget().draw(
+                        &tree.children[index],
+                        renderer,
+                        theme,
+                        style,
+                        children[index],
+                        cursor,
+                        viewport,
+                    );
+                });
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border {
+                            color: frame.border_color,
+                            width: frame.border_width,
+                            radius: radius.into(),
+                        },
+                        ..Default::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            });
+        }
+
+        let bar = self.children.len() - 1;
+        renderer.with_layer(*viewport, |renderer| {
+            self.children[bar].as_widget().draw(
+                &tree.children[bar],
+                renderer,
+                theme,
+                style,
+                children[bar],
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let edge = match tree.state.downcast_ref::<State>().interaction {
+            Interaction::Resizing { edge, .. } => edge,
+            Interaction::Moving { .. } => return mouse::Interaction::Grabbing,
+            Interaction::None => {
+                let Some(position) = cursor.position() else {
+                    return mouse::Interaction::default();
+                };
+                let bar = self.children.len() - 1;
+                let bar_layout = layout.children().nth(bar).unwrap();
+
+                if bar_layout.bounds().contains(position) {
+                    return self.children[bar].as_widget().mouse_interaction(
+                        &tree.children[bar],
+                        bar_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    );
+                }
+
+                let Some(index) = self.hit(layout, position) else {
+                    return mouse::Interaction::default();
+                };
+
+                match self.edge(layout, index, position) {
+                    Dragging::None => {
+                        return self.children[index].as_widget().mouse_interaction(
+                            &tree.children[index],
+                            layout.children().nth(index).unwrap(),
+                            cursor,
+                            viewport,
+                            renderer,
+                        );
+                    }
+                    edge => edge,
+                }
+            }
+        };
+
+        match edge {
+            Dragging::HorizontalLeft | Dragging::HorizontalRight => {
+                mouse::Interaction::ResizingHorizontally
+            }
+            Dragging::VerticalTop | Dragging::VerticalBottom => {
+                mouse::Interaction::ResizingVertically
+            }
+            Dragging::None => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Mdi<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + iced::advanced::Renderer,
+{
+    fn from(mdi: Mdi<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(mdi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn desktop(count: usize) -> Desktop {
+        let mut desktop = Desktop::new();
+        desktop.update(MdiEvent::Area(Size::new(800.0, 600.0)));
+
+        for _ in 0..count {
+            desktop.open(Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)));
+        }
+        desktop
+    }
+
+    fn bounds(desktop: &Desktop, id: usize) -> Rectangle {
+        desktop.get(id).unwrap().bounds
+    }
+
+    #[test]
+    fn tile_places_the_windows_in_a_grid() {
+        let mut desktop = desktop(3);
+        desktop.tile();
+
+        let size = Size::new(400.0, 300.0);
+        assert_eq!(
+            bounds(&desktop, 0),
+            Rectangle::new(Point::new(0.0, 0.0), size)
+        );
+        assert_eq!(
+            bounds(&desktop, 1),
+            Rectangle::new(Point::new(400.0, 0.0), size)
+        );
+        assert_eq!(
+            bounds(&desktop, 2),
+            Rectangle::new(Point::new(0.0, 300.0), size)
+        );
+    }
+
+    #[test]
+    fn tile_skips_minimized_windows() {
+        let mut desktop = desktop(2);
+        desktop.update(MdiEvent::Minimize(0));
+        desktop.tile();
+
+        assert_eq!(
+            bounds(&desktop, 0),
+            Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0))
+        );
+        assert_eq!(
+            bounds(&desktop, 1),
+            Rectangle::new(Point::ORIGIN, Size::new(800.0, 600.0))
+        );
+    }
+
+    #[test]
+    fn cascade_stacks_the_windows_from_back_to_front() {
+        let mut desktop = desktop(3);
+        desktop.update(MdiEvent::ToggleMaximize(1));
+        desktop.cascade();
+
+        let size = Size::new(800.0 * 0.6, 600.0 * 0.6);
+        assert_eq!(
+            bounds(&desktop, 0),
+            Rectangle::new(Point::new(0.0, 0.0), size)
+        );
+        assert_eq!(
+            bounds(&desktop, 2),
+            Rectangle::new(Point::new(30.0, 30.0), size)
+        );
+        assert_eq!(
+            bounds(&desktop, 1),
+            Rectangle::new(Point::new(60.0, 60.0), size)
+        );
+        assert!(desktop.windows().all(|window| !window.maximized));
+    }
+
+    fn moved(position: Point) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved { position })
+    }
+
+    #[test]
+    fn moves_keep_the_title_bar_inside_the_area() {
+        let area = Size::new(800.0, 600.0);
+        let state = State {
+            interaction: Interaction::Moving {
+                id: 0,
+                grab: Vector::new(10.0, 5.0),
+                width: 200.0,
+            },
+        };
+        let origin = Vector::new(100.0, 50.0);
+
+        assert_eq!(
+            state.interact(&moved(Point::new(400.0, 300.0)), origin, area, 24.0),
+            Some(MdiEvent::Move(0, Point::new(290.0, 245.0)))
+        );
+        assert_eq!(
+            state.interact(&moved(Point::new(0.0, 0.0)), origin, area, 24.0),
+            Some(MdiEvent::Move(0, Point::ORIGIN))
+        );
+        assert_eq!(
+            state.interact(&moved(Point::new(1000.0, 900.0)), origin, area, 24.0),
+            Some(MdiEvent::Move(0, Point::new(600.0, 576.0)))
+        );
+
+        let touch = Event::Touch(touch::Event::FingerMoved {
+            id: touch::Finger(0),
+            position: Point::new(400.0, 300.0),
+        });
+        assert_eq!(
+            state.interact(&touch, origin, area, 24.0),
+            Some(MdiEvent::Move(0, Point::new(290.0, 245.0)))
+        );
+    }
+
+    #[test]
+    fn resizes_stop_at_the_origin() {
+        let area = Size::new(800.0, 600.0);
+        let from = Rectangle::new(Point::new(50.0, 40.0), Size::new(200.0, 100.0));
+        let resizing = |edge, press| State {
+            interaction: Interaction::Resizing {
+                id: 1,
+                edge,
+                origin: from,
+                press,
+            },
+        };
+
+        let left = resizing(Dragging::HorizontalLeft, Point::new(50.0, 60.0));
+        assert_eq!(
+            left.interact(
+                &moved(Point::new(-100.0, 60.0)),
+                Vector::new(0.0, 0.0),
+                area,
+                24.0
+            ),
+            Some(MdiEvent::Resize(
+                1,
+                Rectangle::new(Point::new(0.0, 40.0), Size::new(250.0, 100.0))
+            ))
+        );
+        // the minimum size keeps the right edge in place
+        assert_eq!(
+            left.interact(
+                &moved(Point::new(300.0, 60.0)),
+                Vector::new(0.0, 0.0),
+                area,
+                24.0
+            ),
+            Some(MdiEvent::Resize(
+                1,
+                Rectangle::new(Point::new(130.0, 40.0), Size::new(120.0, 100.0))
+            ))
+        );
+
+        let top = resizing(Dragging::VerticalTop, Point::new(100.0, 40.0));
+        assert_eq!(
+            top.interact(
+                &moved(Point::new(100.0, -100.0)),
+                Vector::new(0.0, 0.0),
+                area,
+                24.0
+            ),
+            Some(MdiEvent::Resize(
+                1,
+                Rectangle::new(Point::new(50.0, 0.0), Size::new(200.0, 140.0))
+            ))
+        );
+    }
+
+    #[test]
+    fn only_moves_continue_an_interaction() {
+        let area = Size::new(800.0, 600.0);
+        let moving = State {
+            interaction: Interaction::Moving {
+                id: 0,
+                grab: Vector::new(0.0, 0.0),
+                width: 200.0,
+            },
+        };
+        let pressed = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(
+            moving.interact(&pressed, Vector::new(0.0, 0.0), area, 24.0),
+            None
+        );
+        assert_eq!(
+            State::default().interact(&moved(Point::ORIGIN), Vector::new(0.0, 0.0), area, 24.0),
+            None
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use iced::advanced::graphics::core::Element;
+use iced::widget::{container, pane_grid, Row};
+use iced::Length;
+
+use crate::drag_window::{DragWindow, Gestures, TitleStyle};
+use crate::glyphs::Glyph;
+use crate::panel_button::PanelButtons;
+use crate::theming::focus::FocusThemer;
+use crate::window::TitleBarMetrics;
+use crate::{IconRenderer, PanelTheme};
+
+/// a title bar for the panes of a [`pane_grid`] that looks like the title bar
+/// of the window, with a title and maximize and close buttons
+///
+/// the buttons and double clicks on the title publish the pane messages given
+/// to the builder, the pane grid drags the pane by its title bar
+#[derive(Debug, Clone)]
+pub struct PaneDecoration<Message> {
+    title: Option<String>,
+    title_style: TitleStyle,
+    panel_theme: PanelTheme,
+    inactive_panel_theme: Option<PanelTheme>,
+    panel_buttons: PanelButtons,
+    maximized: bool,
+    on_maximize: Option<Message>,
+    on_close: Option<Message>,
+}
+
+impl<Message> PaneDecoration<Message> {
+    pub fn new() -> Self {
+        PaneDecoration {
+            title: None,
+            title_style: TitleStyle::default(),
+            panel_theme: PanelTheme::default(),
+            inactive_panel_theme: None,
+            panel_buttons: PanelButtons::default().metrics(TitleBarMetrics::COMPACT),
+            maximized: false,
+            on_maximize: None,
+            on_close: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn title_style(mut self, title_style: TitleStyle) -> Self {
+        self.title_style = title_style;
+        self
+    }
+
+    pub fn panel_theme(mut self, panel_theme: PanelTheme) -> Self {
+        self.panel_theme = panel_theme;
+        self
+    }
+
+    /// theme while the window is unfocused, defaults to [`PanelTheme::inactive`]
+    pub fn inactive_panel_theme(mut self, panel_theme: PanelTheme) -> Self {
+        self.inactive_panel_theme = Some(panel_theme);
+        self
+    }
+
+    /// size and icons of the buttons, compact by default
+    pub fn panel_buttons(mut self, panel_buttons: PanelButtons) -> Self {
+        self.panel_buttons = panel_buttons;
+        self
+    }
+
+    /// show the restore icon instead of the maximize icon
+    pub fn maximized(mut self, maximized: bool) -> Self {
+        self.maximized = maximized;
+        self
+    }
+
+    /// show a maximize button, also published on a double click on the title
+    pub fn on_maximize(mut self, message: Message) -> Self {
+        self.on_maximize = Some(message);
+        self
+    }
+
+    /// show a close button
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    pub fn title_bar<'a, Theme, Renderer>(self) -> pane_grid::TitleBar<'a, Message, Theme, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a + IconRenderer + iced::advanced::text::Renderer<Font = iced::Font>,
+        Theme: 'a + container::StyleSheet,
+    {
+        let inactive_panel_theme = self
+            .inactive_panel_theme
+            .unwrap_or_else(|| self.panel_theme.inactive());
+        let height = self.panel_buttons.metrics.height;
+
+        let mut gestures = Gestures::default();
+        if let Some(message) = self.on_maximize.clone() {
+            gestures = gestures.on_double_click(message);
+        }
+
+        let title = DragWindow::label(Length::Fill, height)
+            .title_style(self.title_style)
+            .set_title(self.title)
+            .gestures(gestures);
+
+        let mut buttons: Vec<Element<'a, Message, PanelTheme, Renderer>> = Vec::new();
+        if let Some(message) = self.on_maximize {
+            let glyph = if self.maximized {
+                Glyph::Restore
+            } else {
+                Glyph::Maximize
+            };
+            buttons.push(self.panel_buttons.button(glyph).on_press(message).into());
+        }
+        if let Some(message) = self.on_close {
+            buttons.push(
+                self.panel_buttons
+                    .button(Glyph::Close)
+                    .on_press(message)
+                    .into(),
+            );
+        }
+
+        pane_grid::TitleBar::new(
+            FocusThemer::new(self.panel_theme, inactive_panel_theme, title).title_color(true),
+        )
+        .controls(FocusThemer::new(
+            self.panel_theme,
+            inactive_panel_theme,
+            Row::with_children(buttons).height(height),
+        ))
+        .always_show_controls()
+    }
+}
+
+impl<Message> Default for PaneDecoration<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,123 @@
+use iced::widget::{button, Button};
+
+use crate::glyphs::Glyph;
+#[cfg(feature = "svg")]
+use crate::svgs::IconSet;
+use crate::window::TitleBarMetrics;
+use crate::{IconRenderer, PanelButton, PanelTheme};
+use iced::advanced::graphics::core::Element;
+
+/// the icon of a caption button
+#[derive(Debug, Clone)]
+pub enum CaptionIcon {
+    /// one of the glyphs of the panel buttons, drawn from the icon set
+    Glyph(Glyph),
+    /// any svg, tinted like the panel icons
+    #[cfg(feature = "svg")]
+    Svg(iced::widget::svg::Handle),
+}
+
+impl CaptionIcon {
+    /// the style of the panel button showing this icon
+    pub fn style(&self) -> PanelButton {
+        match self {
+            CaptionIcon::Glyph(Glyph::Minimize) => PanelButton::Minimize,
+            CaptionIcon::Glyph(Glyph::Maximize | Glyph::Restore) => PanelButton::Restore,
+            CaptionIcon::Glyph(Glyph::Close) => PanelButton::Close,
+            CaptionIcon::Glyph(Glyph::Pin) => PanelButton::Pin,
+            CaptionIcon::Glyph(Glyph::Unpin) => PanelButton::Pinned,
+            CaptionIcon::Glyph(Glyph::Add | Glyph::Fullscreen | Glyph::Menu) => PanelButton::Custom,
+            #[cfg(feature = "svg")]
+            CaptionIcon::Svg(_) => PanelButton::Custom,
+        }
+    }
+}
+
+impl From<Glyph> for CaptionIcon {
+    fn from(glyph: Glyph) -> Self {
+        CaptionIcon::Glyph(glyph)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl From<iced::widget::svg::Handle> for CaptionIcon {
+    fn from(handle: iced::widget::svg::Handle) -> Self {
+        CaptionIcon::Svg(handle)
+    }
+}
+
+/// a caption button with the default size and icons of the panel buttons,
+/// drawn with the [`PanelTheme`] so it can be placed in pane title bars or
+/// custom headers
+///
+/// wrap it in a [`FocusThemer`](crate::FocusThemer) to place it in a layout of
+/// the app theme, the themer also dims it while the window is unfocused
+pub fn panel_button<'a, Message, Renderer>(
+    icon: impl Into<CaptionIcon>,
+) -> Button<'a, Message, PanelTheme, Renderer>
+where
+    Renderer: 'a + IconRenderer,
+{
+    PanelButtons::default().button(icon)
+}
+
+/// the size and icons shared by a group of panel buttons
+#[derive(Debug, Clone, Default)]
+pub struct PanelButtons {
+    pub metrics: TitleBarMetrics,
+    #[cfg(feature = "svg")]
+    pub icons: IconSet,
+}
+
+impl PanelButtons {
+    pub fn metrics(mut self, metrics: TitleBarMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn icons(mut self, icons: impl Into<IconSet>) -> Self {
+        self.icons = icons.into();
+        self
+    }
+
+    /// the icon element of a panel button
+    pub fn icon<'a, Message, Renderer>(
+        &self,
+        icon: impl Into<CaptionIcon>,
+    ) -> Element<'a, Message, PanelTheme, Renderer>
+    where
+        Renderer: 'a + IconRenderer,
+    {
+        match icon.into() {
+            #[cfg(feature = "svg")]
+            CaptionIcon::Glyph(glyph) => iced::widget::svg(self.icons.glyph(glyph).clone())
+                .height(self.metrics.icon_size)
+                .into(),
+            #[cfg(not(feature = "svg"))]
+            CaptionIcon::Glyph(glyph) => crate::glyphs::icon(glyph)
+                .size(self.metrics.icon_size)
+                .into(),
+            #[cfg(feature = "svg")]
+            CaptionIcon::Svg(handle) => iced::widget::svg(handle)
+                .height(self.metrics.icon_size)
+                .into(),
+        }
+    }
+
+    /// a panel button with the style that belongs to its icon
+    pub fn button<'a, Message, Renderer>(
+        &self,
+        icon: impl Into<CaptionIcon>,
+    ) -> Button<'a, Message, PanelTheme, Renderer>
+    where
+        Renderer: 'a + IconRenderer,
+    {
+        let icon = icon.into();
+        let style = icon.style();
+
+        button(self.icon(icon))
+            .style(style)
+            .width(self.metrics.button_width)
+    }
+}
@@ -0,0 +1,373 @@
+use std::ops::BitOr;
+
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, touch, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use crate::resizer::{hit_edge, Dragging};
+
+/// the edges of a [`Resizable`] that can be dragged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Edges {
+    pub left: bool,
+    pub right: bool,
+    pub top: bool,
+    pub bottom: bool,
+}
+
+impl Edges {
+    pub const NONE: Self = Self {
+        left: false,
+        right: false,
+        top: false,
+        bottom: false,
+    };
+    pub const LEFT: Self = Self {
+        left: true,
+        ..Self::NONE
+    };
+    pub const RIGHT: Self = Self {
+        right: true,
+        ..Self::NONE
+    };
+    pub const TOP: Self = Self {
+        top: true,
+        ..Self::NONE
+    };
+    pub const BOTTOM: Self = Self {
+        bottom: true,
+        ..Self::NONE
+    };
+    pub const ALL: Self = Self {
+        left: true,
+        right: true,
+        top: true,
+        bottom: true,
+    };
+}
+
+impl BitOr for Edges {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            left: self.left || other.left,
+            right: self.right || other.right,
+            top: self.top || other.top,
+            bottom: self.bottom || other.bottom,
+        }
+    }
+}
+
+pub fn resizable<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_resize: impl 'a + Fn(Size) -> Message,
+) -> Resizable<'a, Message, Theme, Renderer> {
+    Resizable::new(content, on_resize)
+}
+
+/// a panel that is resized by dragging its edges, like sidebars and inspectors
+///
+/// the app keeps the size of the panel, the widget publishes the new size
+/// while an edge is dragged, kept between the minimum and maximum size
+pub struct Resizable<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_resize: Box<dyn Fn(Size) -> Message + 'a>,
+    width: Option<f32>,
+    height: Option<f32>,
+    edges: Edges,
+    handle_width: f32,
+    min_size: Size,
+    max_size: Size,
+}
+
+impl<'a, Message, Theme, Renderer> Resizable<'a, Message, Theme, Renderer> {
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_resize: impl 'a + Fn(Size) -> Message,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_resize: Box::new(on_resize),
+            width: None,
+            height: None,
+            edges: Edges::ALL,
+            handle_width: 5.0,
+            min_size: Size::ZERO,
+            max_size: Size::INFINITY,
+        }
+    }
+
+    /// the width kept by the app, the content decides it when unset
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// the height kept by the app, the content decides it when unset
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// the edges with a resize handle, all of them by default
+    pub fn edges(mut self, edges: Edges) -> Self {
+        self.edges = edges;
+        self
+    }
+
+    /// width of the handles along the inside of the edges
+    pub fn handle_width(mut self, width: f32) -> Self {
+        self.handle_width = width;
+        self
+    }
+
+    pub fn min_size(mut self, size: Size) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    pub fn max_size(mut self, size: Size) -> Self {
+        self.max_size = size;
+        self
+    }
+
+    /// the edge with a handle under the position
+    fn hit_test(&self, bounds: Rectangle, position: Point) -> Dragging {
+        if !bounds.contains(position) {
+            return Dragging::None;
+        }
+
+        hit_edge(bounds, self.handle_width, position, self.edges)
+    }
+}
+
+/// the edge being dragged, with the size and cursor position on press
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    dragging: Option<(Dragging, Size, Point)>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Resizable<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        let content = self.content.as_widget().size();
+
+        Size {
+            width: self.width.map_or(content.width, Length::Fixed),
+            height: self.height.map_or(content.height, Length::Fixed),
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Size { width, height } = Widget::<Message, Theme, Renderer>::size(self);
+        let limits = limits.width(width).height(height);
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, &limits);
+        let size = limits.resolve(width, height, content.size());
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((dragging, origin, press)) = state.dragging {
+                    let Vector { x: dx, y: dy } = position - press;
+                    let mut size = origin;
+
+                    match dragging {
+                        Dragging::HorizontalRight => size.width += dx,
+                        Dragging::HorizontalLeft => size.width -= dx,
+                        Dragging::VerticalBottom => size.height += dy,
+                        Dragging::VerticalTop => size.height -= dy,
+                        Dragging::None => {}
+                    }
+
+                    // the maximum wins when it is below the minimum
+                    let size = Size::new(
+                        size.width.max(self.min_size.width).min(self.max_size.width),
+                        size.height
+                            .max(self.min_size.height)
+                            .min(self.max_size.height),
+                    );
+                    if size != bounds.size() {
+                        shell.publish((self.on_resize)(size));
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position() {
+                    let dragging = self.hit_test(bounds, position);
+
+                    if dragging != Dragging::None {
+                        state.dragging = Some((dragging, bounds.size(), position));
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let dragging = match tree.state.downcast_ref::<State>().dragging {
+            Some((dragging, _, _)) => dragging,
+            None => cursor.position().map_or(Dragging::None, |position| {
+                self.hit_test(layout.bounds(), position)
+            }),
+        };
+
+        match dragging {
+            Dragging::HorizontalLeft | Dragging::HorizontalRight => {
+                mouse::Interaction::ResizingHorizontally
+            }
+            Dragging::VerticalTop | Dragging::VerticalBottom => {
+                mouse::Interaction::ResizingVertically
+            }
+            Dragging::None => self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            ),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Resizable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + iced::advanced::Renderer,
+{
+    fn from(resizable: Resizable<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(resizable)
+    }
+}
@@ -4,17 +4,39 @@ use iced::{
         widget::{tree, Id, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-    event, touch, window, Color, Element, Event, Length, Point, Rectangle, Size, Vector,
+    event, touch, window, Border, Color, Element, Event, Length, Point, Rectangle, Size, Vector,
 };
 
+use crate::frame::{self, Frame};
+use crate::resizable::Edges;
+
 #[derive(Debug, Clone)]
 pub enum ResizeEvent {
     ResizeXY(Size),
     ResizeWindow(Rectangle),
+    /// the window was resized, used to refresh the maximized state when a
+    /// frame or an outside resize area is configured
+    Resized(Size),
+}
+
+/// where the resize hit zones of the window are placed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResizeArea {
+    /// a band of the given width along the inside of the window edges
+    Inside(f32),
+    /// a transparent margin of the given width around the window, keeps the
+    /// whole content interactive but needs a transparent window
+    Outside(f32),
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum Dragging {
+impl Default for ResizeArea {
+    fn default() -> Self {
+        Self::Inside(5.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Dragging {
     HorizontalRight,
     HorizontalLeft,
     VerticalTop,
@@ -25,8 +47,16 @@ enum Dragging {
 #[derive(Debug, Clone)]
 pub(crate) struct ResizeState {
     dragging: Dragging,
+    /// distance between the cursor and the dragged edge when the drag started
+    grab: Vector,
     pub(crate) window_size: Size,
     pub(crate) window_position: Point,
+    pub(crate) maximized: bool,
+    /// the full height of the window while it is shaded to its title bar, kept
+    /// while the window is maximized so unmaximizing returns to the shade
+    pub(crate) shaded: Option<f32>,
+    /// height of the window when it is shaded
+    pub(crate) shade_height: f32,
     show: bool,
 }
 
@@ -34,8 +64,12 @@ impl Default for ResizeState {
     fn default() -> Self {
         Self {
             dragging: Dragging::None,
+            grab: Vector::new(0.0, 0.0),
             window_size: Size::new(0.0, 0.0),
             window_position: Point::new(0.0, 0.0),
+            maximized: false,
+            shaded: None,
+            shade_height: 0.0,
             show: true,
         }
     }
@@ -52,6 +86,9 @@ pub struct Resizer<'a, Message, Theme, Renderer> {
     content: Element<'a, Message, Theme, Renderer>,
     event_handler: Box<dyn Fn(ResizeEvent) -> Message + 'a>,
     id: Option<Id>,
+    frame: Option<Frame>,
+    area: ResizeArea,
+    shade_height: f32,
 }
 
 impl<'a, Message, Theme, Renderer> Resizer<'a, Message, Theme, Renderer> {
@@ -64,6 +101,9 @@ impl<'a, Message, Theme, Renderer> Resizer<'a, Message, Theme, Renderer> {
             content,
             event_handler: Box::new(event_handler),
             id: None,
+            frame: None,
+            area: ResizeArea::default(),
+            shade_height: 0.0,
         }
     }
 
@@ -71,6 +111,98 @@ impl<'a, Message, Theme, Renderer> Resizer<'a, Message, Theme, Renderer> {
         self.id = Some(id);
         self
     }
+
+    /// draw a frame around the content, see [`Frame`]
+    pub fn frame(mut self, frame: Option<Frame>) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// where to put the resize hit zones, see [`ResizeArea`]
+    pub fn area(mut self, area: ResizeArea) -> Self {
+        self.area = area;
+        self
+    }
+
+    /// height of the content that stays visible when the window is shaded,
+    /// usually the title bar
+    pub fn shade_height(mut self, height: f32) -> Self {
+        self.shade_height = height;
+        self
+    }
+
+    /// whether the layout changes with the maximized state, only then the
+    /// maximized state is fetched after a resize
+    fn tracks_maximized(&self) -> bool {
+        self.frame.is_some() || matches!(self.area, ResizeArea::Outside(_))
+    }
+
+    /// the frame to draw for the current state, maximized windows have none
+    fn active_frame(&self, state: &ResizeState) -> Option<Frame> {
+        self.frame.filter(|_| !state.maximized)
+    }
+
+    /// transparent space between the layout bounds and the visible window
+    fn margin(&self, state: &ResizeState) -> f32 {
+        if state.maximized {
+            return 0.0;
+        }
+
+        let frame = self.frame.map_or(0.0, |frame| frame.margin);
+
+        match self.area {
+            ResizeArea::Inside(_) => frame,
+            ResizeArea::Outside(width) => frame.max(width),
+        }
+    }
+
+    /// space between the layout bounds and the content
+    fn inset(&self, state: &ResizeState) -> f32 {
+        self.margin(state)
+            + self
+                .active_frame(state)
+                .map_or(0.0, |frame| frame.border_width)
+    }
+
+    /// the edge to resize when pressing at the given position
+    fn hit_test(&self, state: &ResizeState, layout: Layout<'_>, position: Point) -> Dragging {
+        if !state.show || state.maximized {
+            return Dragging::None;
+        }
+
+        let (bounds, width) = match self.area {
+            ResizeArea::Inside(width) => (layout.children().next().unwrap().bounds(), width),
+            ResizeArea::Outside(_) => (frame::shrink(layout.bounds(), self.margin(state)), 0.0),
+        };
+
+        // only the width of a shaded window can change
+        let edges = if state.shaded.is_some() {
+            Edges::LEFT | Edges::RIGHT
+        } else {
+            Edges::ALL
+        };
+
+        hit_edge(bounds, width, position, edges)
+    }
+}
+
+/// the edge of the bounds in the band of the given width around the position,
+/// only the given edges are hit and positions outside of the bounds hit the
+/// nearest one
+pub(crate) fn hit_edge(bounds: Rectangle, width: f32, position: Point, edges: Edges) -> Dragging {
+    let Point { x, y } = position;
+
+    if edges.left && x < bounds.x + width {
+        Dragging::HorizontalLeft
+    } else if edges.right && x > bounds.x + bounds.width - width {
+        Dragging::HorizontalRight
+    } else if edges.top && y < bounds.y + width {
+        Dragging::VerticalTop
+    } else if edges.bottom && y > bounds.y + bounds.height - width {
+        Dragging::VerticalBottom
+    } else {
+        Dragging::None
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -109,14 +241,22 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let size = limits.max();
+        let state = tree.state.downcast_mut::<ResizeState>();
+        let inset = self.inset(state);
+        // the resizer fills the window, its size is known here before `init` or
+        // the first resize reports it
+        if state.window_size == Size::ZERO {
+            state.window_size = size;
+        }
+        state.shade_height = self.shade_height + inset * 2.0;
 
-        layout::Node::with_children(
-            size,
-            vec![self
-                .content
-                .as_widget()
-                .layout(&mut tree.children[0], renderer, limits)],
-        )
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &limits.shrink(Size::new(inset * 2.0, inset * 2.0)),
+        );
+
+        layout::Node::with_children(size, vec![content.move_to(Point::new(inset, inset))])
     }
 
     fn operate(
@@ -151,6 +291,12 @@ where
     ) -> event::Status {
         let state = tree.state.downcast_mut::<ResizeState>();
         let mut bounds = Rectangle::new(state.window_position, state.window_size);
+        let content_bounds = layout.children().next().unwrap().bounds();
+
+        // the maximized state changed since the last layout
+        if content_bounds.x - layout.bounds().x != self.inset(state) {
+            shell.invalidate_layout();
+        }
 
         let cursor_position = cursor.position();
 
@@ -158,6 +304,10 @@ where
             Event::Window(_, ref event) => match event {
                 window::Event::Resized { width, height } => {
                     state.window_size = Size::new(width.clone() as f32, height.clone() as f32);
+                    if self.tracks_maximized() {
+                        let size = state.window_size;
+                        shell.publish((self.event_handler)(ResizeEvent::Resized(size)));
+                    }
                 }
                 window::Event::Moved { x, y } => {
                     state.window_position = Point::new(x.clone() as f32, y.clone() as f32);
@@ -173,31 +323,34 @@ where
             }) => {
                 let mut size = bounds.size();
                 let Point { x, y } = cursor_position;
+                let grab = state.grab;
 
                 match state.dragging {
                     Dragging::HorizontalRight => {
-                        if x < bounds.width || x > bounds.width {
-                            size.width = x;
+                        if x + grab.x != bounds.width {
+                            size.width = x + grab.x;
                             shell.publish((self.event_handler)(ResizeEvent::ResizeXY(size)));
                         }
                     }
                     Dragging::VerticalBottom => {
-                        if y < bounds.height || y > bounds.y {
-                            size.height = y;
+                        if y + grab.y != bounds.height {
+                            size.height = y + grab.y;
                             shell.publish((self.event_handler)(ResizeEvent::ResizeXY(size)));
                         }
                     }
                     Dragging::HorizontalLeft => {
-                        if x < bounds.x || x > bounds.x {
-                            bounds.width = bounds.width - x;
-                            bounds.x = bounds.x + x;
+                        let dx = x - grab.x;
+                        if dx != 0.0 {
+                            bounds.width = bounds.width - dx;
+                            bounds.x = bounds.x + dx;
                             shell.publish((self.event_handler)(ResizeEvent::ResizeWindow(bounds)));
                         }
                     }
                     Dragging::VerticalTop => {
-                        if y < bounds.y || y > bounds.y {
-                            bounds.height = bounds.height - y;
-                            bounds.y = bounds.y + y;
+                        let dy = y - grab.y;
+                        if dy != 0.0 {
+                            bounds.height = bounds.height - dy;
+                            bounds.y = bounds.y + dy;
                             shell.publish((self.event_handler)(ResizeEvent::ResizeWindow(bounds)));
                         }
                     }
@@ -213,22 +366,20 @@ where
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if state.show & cursor_position.is_some() {
+                if let Some(position) = cursor_position {
+                    let dragging = self.hit_test(state, layout, position);
                     let bounds = layout.bounds();
 
-                    let Point { x, y } = cursor_position.unwrap();
+                    state.grab = match dragging {
+                        Dragging::HorizontalRight | Dragging::VerticalBottom => Vector::new(
+                            bounds.x + bounds.width - position.x,
+                            bounds.y + bounds.height - position.y,
+                        ),
+                        _ => Vector::new(position.x - bounds.x, position.y - bounds.y),
+                    };
 
-                    if x < bounds.x + 5.0 {
-                        state.dragging = Dragging::HorizontalLeft;
-                        return event::Status::Captured;
-                    } else if x > bounds.x + bounds.width - 5.0 {
-                        state.dragging = Dragging::HorizontalRight;
-                        return event::Status::Captured;
-                    } else if y < bounds.y + 5.0 {
-                        state.dragging = Dragging::VerticalTop;
-                        return event::Status::Captured;
-                    } else if y > bounds.y + bounds.height - 5.0 {
-                        state.dragging = Dragging::VerticalBottom;
+                    if dragging != Dragging::None {
+                        state.dragging = dragging;
                         return event::Status::Captured;
                     }
                 }
@@ -258,25 +409,71 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
+        let state = tree.state.downcast_ref::<ResizeState>();
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: bounds,
-                ..Default::default()
-            },
-            Color::TRANSPARENT,
-        );
 
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            style,
-            content_layout,
-            cursor,
-            &viewport,
-        );
+        match (self.frame, self.active_frame(state)) {
+            (_, Some(frame)) => {
+                let frame_bounds = frame::shrink(bounds, self.margin(state));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: frame_bounds,
+                        border: Border {
+                            radius: frame.radius.into(),
+                            ..Border::default()
+                        },
+                        shadow: frame.shadow,
+                    },
+                    frame.background,
+                );
+
+                renderer.with_layer(content_layout.bounds(), |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        style,
+                        content_layout,
+                        cursor,
+                        &viewport,
+                    );
+                });
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: frame_bounds,
+                        border: Border {
+                            color: frame.border_color,
+                            width: frame.border_width,
+                            radius: frame.radius.into(),
+                        },
+                        ..Default::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+            (frame, None) => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bounds,
+                        ..Default::default()
+                    },
+                    frame.map_or(Color::TRANSPARENT, |frame| frame.background),
+                );
+
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    &viewport,
+                );
+            }
+        }
     }
 
     fn mouse_interaction(
@@ -288,24 +485,16 @@ where
         renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<ResizeState>();
-        let cursor_position = cursor.position();
 
-        if state.show & cursor_position.is_some() {
-            let bounds = layout.bounds();
-
-            let Point { x, y } = cursor_position.unwrap();
-
-            if x < bounds.x + 5.0 {
-                return mouse::Interaction::ResizingHorizontally;
-            }
-            if x > bounds.x + bounds.width - 5.0 {
-                return mouse::Interaction::ResizingHorizontally;
-            }
-            if y < bounds.y + 5.0 {
-                return mouse::Interaction::ResizingVertically;
-            }
-            if y > bounds.y + bounds.height - 5.0 {
-                return mouse::Interaction::ResizingVertically;
+        if let Some(position) = cursor.position() {
+            match self.hit_test(state, layout, position) {
+                Dragging::HorizontalLeft | Dragging::HorizontalRight => {
+                    return mouse::Interaction::ResizingHorizontally;
+                }
+                Dragging::VerticalTop | Dragging::VerticalBottom => {
+                    return mouse::Interaction::ResizingVertically;
+                }
+                Dragging::None => {}
             }
         }
 
@@ -345,3 +534,58 @@ where
         Self::new(resizer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn hits_the_edge_under_the_position() {
+        let hit = |x, y| hit_edge(BOUNDS, 5.0, Point::new(x, y), Edges::ALL);
+
+        assert_eq!(hit(2.0, 50.0), Dragging::HorizontalLeft);
+        assert_eq!(hit(98.0, 50.0), Dragging::HorizontalRight);
+        assert_eq!(hit(50.0, 2.0), Dragging::VerticalTop);
+        assert_eq!(hit(50.0, 98.0), Dragging::VerticalBottom);
+        assert_eq!(hit(50.0, 50.0), Dragging::None);
+        // outside of the bounds hits the nearest edge
+        assert_eq!(hit(-10.0, 50.0), Dragging::HorizontalLeft);
+    }
+
+    #[test]
+    fn corners_hit_the_allowed_edge() {
+        let corner = Point::new(2.0, 2.0);
+
+        assert_eq!(
+            hit_edge(BOUNDS, 5.0, corner, Edges::ALL),
+            Dragging::HorizontalLeft
+        );
+        assert_eq!(
+            hit_edge(BOUNDS, 5.0, corner, Edges::LEFT),
+            Dragging::HorizontalLeft
+        );
+        assert_eq!(
+            hit_edge(BOUNDS, 5.0, corner, Edges::TOP),
+            Dragging::VerticalTop
+        );
+        assert_eq!(
+            hit_edge(BOUNDS, 5.0, corner, Edges::RIGHT | Edges::BOTTOM),
+            Dragging::None
+        );
+    }
+
+    #[test]
+    fn no_edges_hit_nothing() {
+        assert_eq!(
+            hit_edge(BOUNDS, 5.0, Point::new(2.0, 50.0), Edges::NONE),
+            Dragging::None
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use iced::widget::svg;
 use lazy_static::lazy_static;
 
+use crate::glyphs::Glyph;
+
 lazy_static! {
     pub static ref MAXIMIZE_SVG: svg::Handle =
         svg::Handle::from_memory(
@@ -22,4 +24,216 @@ lazy_static! {
             "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='m8 8.707l3.646 3.647l.708-.707L8.707 8l3.647-3.646l-.707-.708L8 7.293L4.354 3.646l-.707.708L7.293 8l-3.646 3.646l.707.708L8 8.707z' clip-rule='evenodd'/></svg>".as_bytes(),)
     };
 
+    pub static ref PIN_SVG: svg::Handle = {
+        svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M10.2 1.5l4.3 4.3-.7.7-.9-.4-2.6 2.6.4 2.6-1 1L7 9.6l-4.1 4.1-.7-.7L6.3 8.9 3.6 6.2l1-1 2.6.4 2.6-2.6-.4-.9.7-.7zm.1 1.9L7.5 6.2 5.3 5.9l4.8 4.8-.3-2.2 2.8-2.8-2.3-2.3z' clip-rule='evenodd'/></svg>".as_bytes(),)
+    };
+
+    pub static ref UNPIN_SVG: svg::Handle = {
+        svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M5 1h6v1l-1 1v4l2 2v1H8.5v5h-1v-5H4V9l2-2V3L5 2V1zm2 2v4.4L5.4 9h5.2L9 7.4V3H7z' clip-rule='evenodd'/></svg>".as_bytes(),)
+    };
+
+    pub static ref ADD_SVG: svg::Handle = {
+        svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' d='M14 7v1H8v6H7V8H1V7h6V1h1v6h6z'/></svg>".as_bytes(),)
+    };
+
+    pub static ref FULLSCREEN_SVG: svg::Handle = {
+        svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' d='M3 3h4v1H4v3H3V3zm6 0h4v4h-1V4H9V3zM3 9h1v3h3v1H3V9zm9 0h1v4H9v-1h3V9z'/></svg>".as_bytes(),)
+    };
+
+    pub static ref MENU_SVG: svg::Handle = {
+        svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' d='M2 4h12v1H2V4zm0 3.5h12v1H2v-1zM2 11h12v1H2v-1z'/></svg>".as_bytes(),)
+    };
+
+    static ref CODICONS: IconSet = IconSet {
+        minimize: MINIMIZE_SVG.clone(),
+        maximize: MAXIMIZE_SVG.clone(),
+        restore: RESTORE_SVG.clone(),
+        close: CLOSE_SVG.clone(),
+        pin: PIN_SVG.clone(),
+        unpin: UNPIN_SVG.clone(),
+        add: ADD_SVG.clone(),
+        fullscreen: FULLSCREEN_SVG.clone(),
+        menu: MENU_SVG.clone(),
+    };
+
+    static ref FLUENT: IconSet = IconSet {
+        minimize: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' d='M3 7.5h10v1H3z'/></svg>".as_bytes(),),
+        maximize: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M5 3h6a2 2 0 0 1 2 2v6a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V5a2 2 0 0 1 2-2zm0 1a1 1 0 0 0-1 1v6a1 1 0 0 0 1 1h6a1 1 0 0 0 1-1V5a1 1 0 0 0-1-1H5z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        restore: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M4.5 5h5A1.5 1.5 0 0 1 11 6.5v5A1.5 1.5 0 0 1 9.5 13h-5A1.5 1.5 0 0 1 3 11.5v-5A1.5 1.5 0 0 1 4.5 5zm0 1a.5.5 0 0 0-.5.5v5a.5.5 0 0 0 .5.5h5a.5.5 0 0 0 .5-.5v-5a.5.5 0 0 0-.5-.5h-5zM6.1 3h4.4A2.5 2.5 0 0 1 13 5.5v4.4a1.5 1.5 0 0 1-1 1.4V5.5A1.5 1.5 0 0 0 10.5 4H4.7a1.5 1.5 0 0 1 1.4-1z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        close: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='none' stroke='currentColor' d='M3.5 3.5l9 9m0-9l-9 9'/></svg>".as_bytes(),),
+        ..CODICONS.clone()
+    };
+
+    static ref ADWAITA: IconSet = IconSet {
+        minimize: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' d='M4 10h8v2H4z'/></svg>".as_bytes(),),
+        maximize: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M4 4h8v8H4V4zm2 2v4h4V6H6z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        restore: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M5 5h6v6H5V5zm2 2v2h2V7H7z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        close: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' d='M4.4 3L8 6.6 11.6 3 13 4.4 9.4 8l3.6 3.6-1.4 1.4L8 9.4 4.4 13 3 11.6 6.6 8 3 4.4z'/></svg>".as_bytes(),),
+        ..CODICONS.clone()
+    };
+
+    static ref MACOS: IconSet = IconSet {
+        minimize: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M8 2a6 6 0 1 1 0 12A6 6 0 0 1 8 2zM5 7.5v1h6v-1H5z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        maximize: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M8 2a6 6 0 1 1 0 12A6 6 0 0 1 8 2zM5.5 5.5v3l3-3h-3zm5 5v-3l-3 3h3z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        restore: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M8 2a6 6 0 1 1 0 12A6 6 0 0 1 8 2zM7.5 7.5v-3l-3 3h3zm1 1v3l3-3h-3z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        close: svg::Handle::from_memory(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='16' height='16' viewBox='0 0 16 16'><path fill='currentColor' fill-rule='evenodd' d='M8 2a6 6 0 1 1 0 12A6 6 0 0 1 8 2zM5.9 5.2l-.7.7L7.3 8l-2.1 2.1.7.7L8 8.7l2.1 2.1.7-.7L8.7 8l2.1-2.1-.7-.7L8 7.3 5.9 5.2z' clip-rule='evenodd'/></svg>".as_bytes(),),
+        ..CODICONS.clone()
+    };
+}
+
+/// the bundled icon packs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconPack {
+    /// the vscode codicons
+    #[default]
+    Codicons,
+    /// windows 11 style caption glyphs
+    Fluent,
+    /// gnome adwaita symbolic style caption glyphs
+    Adwaita,
+    /// macos style caption glyphs
+    MacOs,
+}
+
+impl IconPack {
+    pub const ALL: [Self; 4] = [Self::Codicons, Self::Fluent, Self::Adwaita, Self::MacOs];
+}
+
+impl std::fmt::Display for IconPack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            IconPack::Codicons => "Codicons",
+            IconPack::Fluent => "Fluent",
+            IconPack::Adwaita => "Adwaita",
+            IconPack::MacOs => "macOS",
+        })
+    }
+}
+
+/// icons for the caption buttons and a few extra icons for app buttons
+///
+/// every icon can be replaced with any [`svg::Handle`], including file paths
+#[derive(Debug, Clone)]
+pub struct IconSet {
+    pub minimize: svg::Handle,
+    pub maximize: svg::Handle,
+    pub restore: svg::Handle,
+    pub close: svg::Handle,
+    pub pin: svg::Handle,
+    pub unpin: svg::Handle,
+    pub add: svg::Handle,
+    pub fullscreen: svg::Handle,
+    pub menu: svg::Handle,
+}
+
+impl IconSet {
+    /// the icon drawn for the given glyph
+    pub fn glyph(&self, glyph: Glyph) -> &svg::Handle {
+        match glyph {
+            Glyph::Minimize => &self.minimize,
+            Glyph::Maximize => &self.maximize,
+            Glyph::Restore => &self.restore,
+            Glyph::Close => &self.close,
+            Glyph::Pin => &self.pin,
+            Glyph::Unpin => &self.unpin,
+            Glyph::Add => &self.add,
+            Glyph::Fullscreen => &self.fullscreen,
+            Glyph::Menu => &self.menu,
+        }
+    }
+
+    pub fn codicons() -> Self {
+        CODICONS.clone()
+    }
+
+    pub fn fluent() -> Self {
+        FLUENT.clone()
+    }
+
+    pub fn adwaita() -> Self {
+        ADWAITA.clone()
+    }
+
+    pub fn macos() -> Self {
+        MACOS.clone()
+    }
+
+    pub fn minimize(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.minimize = icon.into();
+        self
+    }
+
+    pub fn maximize(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.maximize = icon.into();
+        self
+    }
+
+    pub fn restore(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.restore = icon.into();
+        self
+    }
+
+    pub fn close(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.close = icon.into();
+        self
+    }
+
+    pub fn pin(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.pin = icon.into();
+        self
+    }
+
+    pub fn unpin(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.unpin = icon.into();
+        self
+    }
+
+    pub fn add(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.add = icon.into();
+        self
+    }
+
+    pub fn fullscreen(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.fullscreen = icon.into();
+        self
+    }
+
+    pub fn menu(mut self, icon: impl Into<svg::Handle>) -> Self {
+        self.menu = icon.into();
+        self
+    }
+}
+
+impl Default for IconSet {
+    fn default() -> Self {
+        Self::codicons()
+    }
+}
+
+impl From<IconPack> for IconSet {
+    fn from(pack: IconPack) -> Self {
+        match pack {
+            IconPack::Codicons => Self::codicons(),
+            IconPack::Fluent => Self::fluent(),
+            IconPack::Adwaita => Self::adwaita(),
+            IconPack::MacOs => Self::macos(),
+        }
+    }
 }
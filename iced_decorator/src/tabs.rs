@@ -0,0 +1,891 @@
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer,
+        text::{LineHeight, Shaping},
+        widget::{tree, Tree},
+        Clipboard, Layout, Shell, Text, Widget,
+    },
+    alignment, event, touch, Border, Element, Event, Length, Pixels, Point, Rectangle, Size,
+    Vector,
+};
+
+use crate::drag_window::{Gestures, Press};
+use crate::glyphs::Glyph;
+use crate::panel_button::PanelButtons;
+use crate::tear_off::{outside, window_size, TearOffEvent};
+use crate::{IconRenderer, PanelTheme};
+
+const MIN_TAB_WIDTH: f32 = 80.0;
+const MAX_TAB_WIDTH: f32 = 220.0;
+/// draggable space between the tabs
+const TAB_GAP: f32 = 2.0;
+/// space between the top of the title bar and the tabs
+const TAB_MARGIN: f32 = 4.0;
+const TAB_PADDING: f32 = 10.0;
+const TAB_RADIUS: f32 = 6.0;
+const CLOSE_SIZE: f32 = 16.0;
+/// distance scrolled for each line of the mouse wheel
+const SCROLL_STEP: f32 = 40.0;
+
+/// the messages of a [`TabStrip`], the app keeps the tabs and applies them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabEvent {
+    Select(usize),
+    Close(usize),
+    /// the tab was dragged from one position to another
+    Move {
+        from: usize,
+        to: usize,
+    },
+    /// the new tab button was pressed
+    New,
+}
+
+pub fn tab_strip<'a, Message>(
+    tabs: impl IntoIterator<Item = impl Into<String>>,
+    active: usize,
+    on_event: impl 'a + Fn(TabEvent) -> Message,
+) -> TabStrip<'a, Message> {
+    TabStrip::new(tabs, active, on_event)
+}
+
+/// browser style tabs for the title bar, drawn with the [`PanelTheme`] and the
+/// icons of the [`PanelButtons`]
+///
+/// the gaps between and after the tabs drag the window and take the gestures
+/// like the title, tabs are reordered by dragging them and scrolled with the
+/// mouse wheel when they do not fit
+pub struct TabStrip<'a, Message> {
+    tabs: Vec<String>,
+    active: usize,
+    on_event: Box<dyn Fn(TabEvent) -> Message + 'a>,
+    on_drag: Option<Message>,
+    drag_threshold: Option<f32>,
+    gestures: Gestures<Message>,
+    on_tear_off: Option<Box<dyn Fn(usize, TearOffEvent) -> Message + 'a>>,
+    closable: bool,
+    new_tab: bool,
+    text_size: Option<Pixels>,
+    panel_buttons: PanelButtons,
+}
+
+impl<'a, Message> TabStrip<'a, Message> {
+    pub fn new(
+        tabs: impl IntoIterator<Item = impl Into<String>>,
+        active: usize,
+        on_event: impl 'a + Fn(TabEvent) -> Message,
+    ) -> Self {
+        TabStrip {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            active,
+            on_event: Box::new(on_event),
+            on_drag: None,
+            drag_threshold: None,
+            gestures: Gestures::default(),
+            on_tear_off: None,
+            closable: true,
+            new_tab: true,
+            text_size: None,
+            panel_buttons: PanelButtons::default(),
+        }
+    }
+
+    /// published when the gaps are pressed, the [`Window`](crate::window::Window)
+    /// sets it to its window drag along with its threshold and gestures
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// only drag the window from the gaps once the pointer moved this far while
+    /// pressed, tabs are dragged after the same distance
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = Some(threshold);
+        self
+    }
+
+    /// messages published for clicks and scrolling on the gaps
+    pub fn gestures(mut self, gestures: Gestures<Message>) -> Self {
+        self.gestures = gestures;
+        self
+    }
+
+    /// show close buttons on the tabs
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// show the new tab button after the tabs
+    pub fn new_tab(mut self, new_tab: bool) -> Self {
+        self.new_tab = new_tab;
+        self
+    }
+
+    /// published with the index of the tab and [`TearOffEvent::DraggedOut`] when
+    /// a tab is dragged out of the window, see
+    /// [`tear_off::locate`](crate::tear_off::locate)
+    pub fn on_tear_off(
+        mut self,
+        on_tear_off: impl 'a + Fn(usize, TearOffEvent) -> Message,
+    ) -> Self {
+        self.on_tear_off = Some(Box::new(on_tear_off));
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// the icons of the close and new tab buttons, the
+    /// [`Window`](crate::window::Window) sets it to its own panel buttons
+    pub fn panel_buttons(mut self, panel_buttons: PanelButtons) -> Self {
+        self.panel_buttons = panel_buttons;
+        self
+    }
+
+    fn geometry(&self, bounds: Rectangle) -> Geometry {
+        let new_tab = if self.new_tab { bounds.height } else { 0.0 };
+        let available = (bounds.width - new_tab - TAB_GAP).max(0.0);
+        let count = self.tabs.len().max(1) as f32;
+
+        let tab_width = (available / count - TAB_GAP).clamp(MIN_TAB_WIDTH, MAX_TAB_WIDTH);
+        let content = self.tabs.len() as f32 * (tab_width + TAB_GAP);
+        let tabs = Rectangle {
+            width: content.min(available),
+            ..bounds
+        };
+
+        Geometry {
+            tab_width,
+            max_offset: (content - tabs.width).max(0.0),
+            new_tab: Rectangle {
+                x: tabs.x + tabs.width + TAB_GAP,
+                width: new_tab,
+                ..bounds
+            },
+            tabs,
+        }
+    }
+
+    /// the part of the strip under the position
+    fn hit(&self, bounds: Rectangle, offset: f32, position: Point) -> Hit {
+        let geometry = self.geometry(bounds);
+
+        if self.new_tab && geometry.new_tab.contains(position) {
+            return Hit::New;
+        }
+        if !geometry.tabs.contains(position) {
+            return Hit::Gap;
+        }
+
+        (0..self.tabs.len())
+            .map(|index| (index, geometry.tab(index, offset)))
+            .find(|(_, tab)| tab.contains(position))
+            .map_or(Hit::Gap, |(index, tab)| {
+                if self.closable && close_bounds(tab).contains(position) {
+                    Hit::Close(index)
+                } else {
+                    Hit::Tab(index)
+                }
+            })
+    }
+}
+
+impl<'a, Message: Clone> TabStrip<'a, Message> {
+    /// handles the event without the renderer, the tabs and buttons get it
+    /// first and the gaps take the rest
+    fn update(
+        &self,
+        state: &mut State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        state.press.reset(event);
+
+        let geometry = self.geometry(bounds);
+        state.offset = state.offset.min(geometry.max_offset);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                match self.hit(bounds, state.offset, position) {
+                    Hit::Close(index) => shell.publish((self.on_event)(TabEvent::Close(index))),
+                    Hit::New => shell.publish((self.on_event)(TabEvent::New)),
+                    Hit::Tab(index) => {
+                        if index != self.active {
+                            shell.publish((self.on_event)(TabEvent::Select(index)));
+                        }
+                        state.pressed = Some(Pressed {
+                            index,
+                            origin: position,
+                            dragging: false,
+                        });
+                    }
+                    Hit::Gap => return self.gap(state, event, bounds, cursor, shell),
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(pressed) = &mut state.pressed {
+                    let threshold = self.drag_threshold.unwrap_or(0.0);
+                    if !pressed.dragging && pressed.origin.distance(*position) >= threshold {
+                        pressed.dragging = true;
+                    }
+
+                    if let Some(on_tear_off) = &self.on_tear_off {
+                        if pressed.dragging
+                            && state
+                                .window
+                                .is_some_and(|window| outside(window, *position))
+                        {
+                            shell.publish(on_tear_off(
+                                pressed.index,
+                                TearOffEvent::DraggedOut(*position),
+                            ));
+                            state.pressed = None;
+                            return event::Status::Captured;
+                        }
+                    }
+
+                    if pressed.dragging {
+                        let slot = geometry.slot(position.x, state.offset, self.tabs.len());
+
+                        if slot != pressed.index {
+                            shell.publish((self.on_event)(TabEvent::Move {
+                                from: pressed.index,
+                                to: slot,
+                            }));
+                            pressed.index = slot;
+                        }
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.pressed.take().is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if cursor.is_over(bounds) && geometry.max_offset > 0.0 {
+                    let delta = match *delta {
+                        mouse::ScrollDelta::Lines { x, y } => (x + y) * SCROLL_STEP,
+                        mouse::ScrollDelta::Pixels { x, y } => x + y,
+                    };
+
+                    state.offset = (state.offset - delta).clamp(0.0, geometry.max_offset);
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        self.gap(state, event, bounds, cursor, shell)
+    }
+
+    /// the gestures and window drag of the gaps, with the same threshold as
+    /// the title bar
+    fn gap(
+        &self,
+        state: &mut State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let over_gap = cursor
+            .position_over(bounds)
+            .is_some_and(|position| self.hit(bounds, state.offset, position) == Hit::Gap);
+
+        if over_gap {
+            if let Some(message) = self
+                .gestures
+                .message(event, cursor, bounds, &mut state.press)
+            {
+                state.press.origin = None;
+                shell.publish(message);
+                return event::Status::Captured;
+            }
+        }
+
+        if let Some(message) = &self.on_drag {
+            if state
+                .press
+                .update(event, cursor, bounds, &[], self.drag_threshold)
+            {
+                shell.publish(message.clone());
+            }
+        }
+        event::Status::Ignored
+    }
+}
+
+/// where the tabs and the new tab button are placed
+struct Geometry {
+    tab_width: f32,
+    /// the visible part of the tabs
+    tabs: Rectangle,
+    new_tab: Rectangle,
+    /// how far the tabs can be scrolled
+    max_offset: f32,
+}
+
+impl Geometry {
+    fn tab(&self, index: usize, offset: f32) -> Rectangle {
+        Rectangle {
+            x: self.tabs.x + TAB_GAP + index as f32 * (self.tab_width + TAB_GAP) - offset,
+            y: self.tabs.y + TAB_MARGIN,
+            width: self.tab_width,
+            height: (self.tabs.height - TAB_MARGIN).max(0.0),
+        }
+    }
+
+    /// the position a tab dragged to the x coordinate is moved to
+    fn slot(&self, x: f32, offset: f32, count: usize) -> usize {
+        let slot = ((x - self.tabs.x + offset) / (self.tab_width + TAB_GAP)).floor();
+
+        (slot.max(0.0) as usize).min(count.saturating_sub(1))
+    }
+}
+
+fn close_bounds(tab: Rectangle) -> Rectangle {
+    Rectangle {
+        x: tab.x + tab.width - TAB_PADDING / 2.0 - CLOSE_SIZE,
+        y: tab.center_y() - CLOSE_SIZE / 2.0,
+        width: CLOSE_SIZE,
+        height: CLOSE_SIZE,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Hit {
+    Tab(usize),
+    Close(usize),
+    New,
+    Gap,
+}
+
+/// a tab held down by the pointer
+#[derive(Debug, Clone, Copy)]
+struct Pressed {
+    index: usize,
+    origin: Point,
+    dragging: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    offset: f32,
+    pressed: Option<Pressed>,
+    /// the size of the window, read while a tab is pressed
+    window: Option<Size>,
+    /// a press on the gaps waiting to drag the window
+    press: Press,
+}
+
+impl<'a, Message, Renderer> Widget<Message, PanelTheme, Renderer> for TabStrip<'a, Message>
+where
+    Message: Clone,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font> + IconRenderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(Length::Fill, Length::Fill, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        self.update(
+            tree.state.downcast_mut::<State>(),
+            &event,
+            layout.bounds(),
+            cursor,
+            shell,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &PanelTheme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let geometry = self.geometry(bounds);
+        let offset = state.offset.min(geometry.max_offset);
+        let hit = cursor
+            .position_over(bounds)
+            .map_or(Hit::Gap, |position| self.hit(bounds, offset, position));
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let font = renderer.default_font();
+
+        let text = |content, bounds, alignment| Text {
+            content,
+            bounds,
+            size,
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: alignment,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: Shaping::Advanced,
+        };
+
+        // the close and new tab glyphs are drawn like the icons of the panel buttons
+        let icon = |renderer: &mut Renderer, glyph: Glyph, bounds: Rectangle| {
+            let icon = self.panel_buttons.icon::<Message, Renderer>(glyph);
+            let node = layout::Node::new(bounds.size());
+
+            icon.as_widget().draw(
+                &Tree::new(&icon),
+                renderer,
+                theme,
+                style,
+                Layout::with_offset(Vector::new(bounds.x, bounds.y), &node),
+                cursor,
+                viewport,
+            );
+        };
+
+        renderer.with_layer(geometry.tabs, |renderer| {
+            for (index, title) in self.tabs.iter().enumerate() {
+                let tab = geometry.tab(index, offset);
+                let background = if index == self.active {
+                    theme.tab_active
+                } else if hit == Hit::Tab(index) || hit == Hit::Close(index) {
+                    theme.tab_hover
+                } else {
+                    theme.tab
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab,
+                        border: Border {
+                            radius: [TAB_RADIUS, TAB_RADIUS, 0.0, 0.0].into(),
+                            ..Border::default()
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+
+                let close = self.closable.then(|| close_bounds(tab));
+                let title_bounds = Rectangle {
+                    x: tab.x + TAB_PADDING,
+                    width: close.map_or(tab.x + tab.width - TAB_PADDING, |close| close.x)
+                        - tab.x
+                        - TAB_PADDING,
+                    ..tab
+                };
+
+                renderer.fill_text(
+                    text(
+                        title.as_str(),
+                        title_bounds.size(),
+                        alignment::Horizontal::Left,
+                    ),
+                    Point::new(title_bounds.x, title_bounds.center_y()),
+                    theme.title,
+                    title_bounds,
+                );
+
+                if let Some(close) = close {
+                    if hit == Hit::Close(index) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: close,
+                                border: Border {
+                                    radius: (CLOSE_SIZE / 2.0).into(),
+                                    ..Border::default()
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            theme.restore_hover,
+                        );
+                    }
+
+                    icon(renderer, Glyph::Close, close);
+                }
+            }
+        });
+
+        if self.new_tab {
+            let new_tab = geometry.new_tab;
+
+            if hit == Hit::New {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: new_tab,
+                        ..renderer::Quad::default()
+                    },
+                    theme.tab_hover,
+                );
+            }
+
+            icon(
+                renderer,
+                Glyph::Add,
+                Rectangle::new(
+                    new_tab.center() - Vector::new(CLOSE_SIZE / 2.0, CLOSE_SIZE / 2.0),
+                    Size::new(CLOSE_SIZE, CLOSE_SIZE),
+                ),
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.pressed.is_some_and(|pressed| pressed.dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let bounds = layout.bounds();
+        match cursor.position_over(bounds) {
+            Some(position) if self.hit(bounds, state.offset, position) != Hit::Gap => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, PanelTheme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.on_tear_off.is_some() && state.pressed.is_some() {
+            Some(window_size(&mut state.window))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<TabStrip<'a, Message>>
+    for Element<'a, Message, PanelTheme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font> + IconRenderer,
+{
+    fn from(tabs: TabStrip<'a, Message>) -> Self {
+        Element::new(tabs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Tab(TabEvent),
+        Drag,
+        DoubleClick,
+        MiddleClick,
+        TearOff(usize, TearOffEvent),
+    }
+
+    fn strip(count: usize) -> TabStrip<'static, Message> {
+        TabStrip::new((0..count).map(|index| index.to_string()), 0, Message::Tab)
+    }
+
+    /// feeds the events with the cursor at their positions through the strip
+    /// and collects the messages
+    fn run(
+        strip: &TabStrip<'_, Message>,
+        state: &mut State,
+        events: &[(Event, Point)],
+    ) -> Vec<Message> {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(1000.0, 30.0));
+        let mut messages = Vec::new();
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut messages);
+            let _ = strip.update(
+                state,
+                event,
+                bounds,
+                mouse::Cursor::Available(*position),
+                &mut shell,
+            );
+        }
+        messages
+    }
+
+    fn pressed(button: mouse::Button, position: Point) -> (Event, Point) {
+        (Event::Mouse(mouse::Event::ButtonPressed(button)), position)
+    }
+
+    fn released(position: Point) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            position,
+        )
+    }
+
+    fn moved(position: Point) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        )
+    }
+
+    /// past the tabs and the new tab button of `strip(3)`
+    const GAP: Point = Point::new(800.0, 15.0);
+
+    #[test]
+    fn geometry_fits_tabs() {
+        let geometry = strip(3).geometry(Rectangle::new(Point::ORIGIN, Size::new(1000.0, 30.0)));
+
+        assert_eq!(geometry.tab_width, MAX_TAB_WIDTH);
+        assert_eq!(geometry.tabs.width, 3.0 * (MAX_TAB_WIDTH + TAB_GAP));
+        assert_eq!(geometry.max_offset, 0.0);
+        assert_eq!(geometry.new_tab.x, geometry.tabs.width + TAB_GAP);
+        assert_eq!(geometry.new_tab.width, 30.0);
+    }
+
+    #[test]
+    fn geometry_scrolls_overflowing_tabs() {
+        let geometry = strip(10).geometry(Rectangle::new(Point::ORIGIN, Size::new(500.0, 30.0)));
+
+        assert_eq!(geometry.tab_width, MIN_TAB_WIDTH);
+        assert_eq!(geometry.tabs.width, 500.0 - 30.0 - TAB_GAP);
+        assert_eq!(
+            geometry.max_offset,
+            10.0 * (MIN_TAB_WIDTH + TAB_GAP) - geometry.tabs.width
+        );
+        assert_eq!(geometry.new_tab.x, geometry.tabs.width + TAB_GAP);
+    }
+
+    #[test]
+    fn geometry_without_new_tab() {
+        let geometry = strip(2)
+            .new_tab(false)
+            .geometry(Rectangle::new(Point::ORIGIN, Size::new(300.0, 30.0)));
+
+        assert_eq!(geometry.new_tab.width, 0.0);
+        assert_eq!(geometry.tab_width, (300.0 - TAB_GAP) / 2.0 - TAB_GAP);
+    }
+
+    #[test]
+    fn tabs_follow_the_offset() {
+        let geometry = strip(3).geometry(Rectangle::new(
+            Point::new(10.0, 0.0),
+            Size::new(1000.0, 30.0),
+        ));
+
+        assert_eq!(geometry.tab(0, 0.0).x, 10.0 + TAB_GAP);
+        assert_eq!(
+            geometry.tab(1, 0.0).x,
+            10.0 + TAB_GAP + MAX_TAB_WIDTH + TAB_GAP
+        );
+        assert_eq!(geometry.tab(1, 50.0).x, geometry.tab(1, 0.0).x - 50.0);
+        assert_eq!(geometry.tab(0, 0.0).y, TAB_MARGIN);
+    }
+
+    #[test]
+    fn slot() {
+        let geometry = strip(3).geometry(Rectangle::new(Point::ORIGIN, Size::new(1000.0, 30.0)));
+        let step = MAX_TAB_WIDTH + TAB_GAP;
+
+        assert_eq!(geometry.slot(10.0, 0.0, 3), 0);
+        assert_eq!(geometry.slot(step + 10.0, 0.0, 3), 1);
+        // past the ends
+        assert_eq!(geometry.slot(-50.0, 0.0, 3), 0);
+        assert_eq!(geometry.slot(5000.0, 0.0, 3), 2);
+        // scrolled
+        assert_eq!(geometry.slot(10.0, step, 3), 1);
+        // no tabs
+        assert_eq!(geometry.slot(10.0, 0.0, 0), 0);
+    }
+
+    #[test]
+    fn gap_press_drags_the_window() {
+        let strip = strip(3).on_drag(Message::Drag);
+        let mut state = State::default();
+
+        assert_eq!(
+            run(&strip, &mut state, &[pressed(mouse::Button::Left, GAP)]),
+            vec![Message::Drag]
+        );
+    }
+
+    #[test]
+    fn gap_drag_waits_for_the_threshold() {
+        let strip = strip(3).on_drag(Message::Drag).drag_threshold(8.0);
+        let mut state = State::default();
+
+        assert!(run(
+            &strip,
+            &mut state,
+            &[
+                pressed(mouse::Button::Left, GAP),
+                moved(Point::new(803.0, 15.0))
+            ]
+        )
+        .is_empty());
+        assert_eq!(
+            run(&strip, &mut state, &[moved(Point::new(810.0, 15.0))]),
+            vec![Message::Drag]
+        );
+
+        // released before the threshold, moving again does not drag
+        assert!(run(
+            &strip,
+            &mut state,
+            &[
+                pressed(mouse::Button::Left, GAP),
+                released(GAP),
+                moved(Point::new(820.0, 15.0))
+            ]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn gap_gestures() {
+        let strip = strip(3)
+            .on_drag(Message::Drag)
+            .drag_threshold(8.0)
+            .gestures(
+                Gestures::default()
+                    .on_double_click(Message::DoubleClick)
+                    .on_middle_click(Message::MiddleClick),
+            );
+        let mut state = State::default();
+
+        assert_eq!(
+            run(
+                &strip,
+                &mut state,
+                &[
+                    pressed(mouse::Button::Left, GAP),
+                    released(GAP),
+                    pressed(mouse::Button::Left, GAP),
+                    // the double click does not drag the window
+                    moved(Point::new(820.0, 15.0)),
+                    pressed(mouse::Button::Middle, GAP),
+                ]
+            ),
+            vec![Message::DoubleClick, Message::MiddleClick]
+        );
+
+        // the tabs take their presses
+        assert!(run(
+            &strip,
+            &mut state,
+            &[pressed(mouse::Button::Middle, Point::new(100.0, 15.0))]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn tabs_are_selected_and_reordered() {
+        let strip = strip(3).on_drag(Message::Drag).drag_threshold(8.0);
+        let mut state = State::default();
+
+        assert_eq!(
+            run(
+                &strip,
+                &mut state,
+                &[
+                    pressed(mouse::Button::Left, Point::new(300.0, 15.0)),
+                    moved(Point::new(454.0, 15.0)),
+                    released(Point::new(454.0, 15.0)),
+                ]
+            ),
+            vec![
+                Message::Tab(TabEvent::Select(1)),
+                Message::Tab(TabEvent::Move { from: 1, to: 2 }),
+            ]
+        );
+        assert_eq!(
+            run(
+                &strip,
+                &mut state,
+                &[pressed(mouse::Button::Left, Point::new(690.0, 15.0))]
+            ),
+            vec![Message::Tab(TabEvent::New)]
+        );
+    }
+
+    #[test]
+    fn tabs_tear_off_outside_the_window() {
+        let strip = strip(3).on_tear_off(Message::TearOff);
+        let mut state = State {
+            window: Some(Size::new(1000.0, 600.0)),
+            ..State::default()
+        };
+        let outside = Point::new(300.0, 800.0);
+
+        assert_eq!(
+            run(
+                &strip,
+                &mut state,
+                &[
+                    pressed(mouse::Button::Left, Point::new(100.0, 15.0)),
+                    moved(outside),
+                    // the press is given up once the tab left
+                    moved(Point::new(454.0, 15.0)),
+                ]
+            ),
+            vec![Message::TearOff(0, TearOffEvent::DraggedOut(outside))]
+        );
+    }
+}
@@ -0,0 +1,538 @@
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, touch,
+    window::{self, Id, Position},
+    Command, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use crate::window::TitleBarMetrics;
+
+/// distance the pointer moves past the window bounds before content is torn off
+const TEAR_OFF_MARGIN: f32 = 8.0;
+/// where the pointer grabs the title bar of a window opened for torn off content
+const GRAB_OFFSET: f32 = 40.0;
+
+/// the messages the app moves torn off content between windows on, published by
+/// [`TearOff`], [`TabStrip`](crate::tabs::TabStrip) and [`Dock`](crate::dock::Dock)
+/// and returned by [`locate`] and [`redock`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TearOffEvent {
+    /// the content was dragged out of its window at the cursor position inside
+    /// the window, turn it into a screen position with [`locate`]
+    DraggedOut(Point),
+    /// the screen position to open the window of the content at with
+    /// [`open_window`], `None` when the window position is not known, like on
+    /// Wayland
+    Detach(Option<Point>),
+    /// the window of torn off content was dropped on the title bar of the target
+    /// window, move the content back and close the moved window
+    Redock { moved: Id, target: Id },
+}
+
+/// the position was dragged past the bounds of the window
+pub(crate) fn outside(window: Size, position: Point) -> bool {
+    let bounds = Rectangle {
+        x: -TEAR_OFF_MARGIN,
+        y: -TEAR_OFF_MARGIN,
+        width: window.width + TEAR_OFF_MARGIN * 2.0,
+        height: window.height + TEAR_OFF_MARGIN * 2.0,
+    };
+
+    !bounds.contains(position)
+}
+
+/// turns the cursor position of [`TearOffEvent::DraggedOut`] into a screen
+/// position and publishes it as [`TearOffEvent::Detach`]
+pub fn locate<Message: 'static>(
+    window: Id,
+    cursor: Point,
+    on_event: impl FnOnce(TearOffEvent) -> Message + 'static,
+) -> Command<Message> {
+    window::fetch_position(window, move |position| {
+        on_event(TearOffEvent::Detach(
+            position.map(|position| position + Vector::new(cursor.x, cursor.y)),
+        ))
+    })
+}
+
+/// opens an undecorated window for torn off content with its title bar under
+/// the screen position, the app draws it with a [`Window`](crate::window::Window)
+/// with [`Window::id`](crate::window::Window::id) set to the returned id and runs
+/// [`init_for`](crate::window::init_for) once it sees the window opened
+pub fn open_window<Message: 'static>(position: Point, size: Size) -> (Id, Command<Message>) {
+    let id = Id::unique();
+    let spawn = window::spawn(
+        id,
+        window::Settings {
+            size,
+            position: Position::Specific(Point::new(
+                position.x - GRAB_OFFSET,
+                position.y - TitleBarMetrics::DEFAULT.height / 2.0,
+            )),
+            decorations: false,
+            ..window::Settings::default()
+        },
+    );
+
+    (id, spawn)
+}
+
+/// a [`TearOffEvent::Redock`] when the window moved to the screen position
+/// lies on the title bar of the target window with the bounds
+pub fn redock(
+    moved: Id,
+    position: Point,
+    target: Id,
+    bounds: Rectangle,
+    metrics: TitleBarMetrics,
+) -> Option<TearOffEvent> {
+    let grab = Point::new(position.x + GRAB_OFFSET, position.y + metrics.height / 2.0);
+    let title_bar = Rectangle {
+        height: metrics.height,
+        ..bounds
+    };
+
+    title_bar
+        .contains(grab)
+        .then_some(TearOffEvent::Redock { moved, target })
+}
+
+pub fn tear_off<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_tear_off: impl 'a + Fn(TearOffEvent) -> Message,
+) -> TearOff<'a, Message, Theme, Renderer> {
+    TearOff::new(content, on_tear_off)
+}
+
+/// publishes [`TearOffEvent::DraggedOut`] when a press on the content that the
+/// content does not capture is dragged out of the window, like the header of a
+/// panel
+pub struct TearOff<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_tear_off: Box<dyn Fn(TearOffEvent) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TearOff<'a, Message, Theme, Renderer> {
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_tear_off: impl 'a + Fn(TearOffEvent) -> Message,
+    ) -> Self {
+        TearOff {
+            content: content.into(),
+            on_tear_off: Box::new(on_tear_off),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    pressed: bool,
+    /// the size of the window, read while pressed
+    window: Option<Size>,
+}
+
+impl State {
+    /// the tear off the event completes and whether it is captured, `status`
+    /// is how the content handled the event
+    fn update(
+        &mut self,
+        event: &Event,
+        status: event::Status,
+        cursor: mouse::Cursor,
+        bounds: Rectangle,
+    ) -> (event::Status, Option<TearOffEvent>) {
+        match *event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored && cursor.is_over(bounds) =>
+            {
+                self.pressed = true;
+                (event::Status::Captured, None)
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if self.pressed && self.window.is_some_and(|window| outside(window, position)) =>
+            {
+                self.pressed = false;
+                (
+                    event::Status::Captured,
+                    Some(TearOffEvent::DraggedOut(position)),
+                )
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                self.pressed = false;
+                (status, None)
+            }
+            _ => (status, None),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TearOff<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+        let (status, tear_off) =
+            tree.state
+                .downcast_mut::<State>()
+                .update(&event, status, cursor, layout.bounds());
+
+        if let Some(tear_off) = tear_off {
+            shell.publish((self.on_tear_off)(tear_off));
+        }
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().pressed {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+        let content =
+            self.content
+                .as_widget_mut()
+                .overlay(&mut children[0], layout, renderer, translation);
+
+        if !state.pressed {
+            return content;
+        }
+
+        let window = window_size(&mut state.window);
+        Some(match content {
+            Some(content) => overlay::Group::with_children(vec![content, window]).overlay(),
+            None => window,
+        })
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TearOff<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + iced::advanced::Renderer,
+{
+    fn from(tear_off: TearOff<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tear_off)
+    }
+}
+
+/// an empty overlay that reads the size of the window, widgets only see their
+/// viewport while overlays are laid out in the window bounds
+pub(crate) fn window_size<'a, Message, Theme, Renderer>(
+    size: &'a mut Option<Size>,
+) -> overlay::Element<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    overlay::Element::new(Box::new(WindowSize { size }))
+}
+
+struct WindowSize<'a> {
+    size: &'a mut Option<Size>,
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for WindowSize<'a>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        *self.size = Some(bounds);
+        layout::Node::new(Size::ZERO)
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outside_the_window() {
+        let window = Size::new(800.0, 600.0);
+
+        assert!(!outside(window, Point::new(400.0, 300.0)));
+        // within the margin
+        assert!(!outside(window, Point::new(-TEAR_OFF_MARGIN / 2.0, 300.0)));
+        assert!(!outside(
+            window,
+            Point::new(400.0, 600.0 + TEAR_OFF_MARGIN / 2.0)
+        ));
+        // past the margin
+        assert!(outside(window, Point::new(-TEAR_OFF_MARGIN * 2.0, 300.0)));
+        assert!(outside(
+            window,
+            Point::new(800.0 + TEAR_OFF_MARGIN * 2.0, 300.0)
+        ));
+        assert!(outside(window, Point::new(400.0, -TEAR_OFF_MARGIN * 2.0)));
+        assert!(outside(
+            window,
+            Point::new(400.0, 600.0 + TEAR_OFF_MARGIN * 2.0)
+        ));
+    }
+
+    #[test]
+    fn presses_dragged_out_of_the_window_tear_off() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 30.0));
+        let header = Point::new(50.0, 15.0);
+        let outside = Point::new(50.0, 700.0);
+        let moved = |position| Event::Mouse(mouse::Event::CursorMoved { position });
+        let mut state = State {
+            window: Some(Size::new(800.0, 600.0)),
+            ..State::default()
+        };
+
+        assert_eq!(
+            state.update(
+                &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                event::Status::Ignored,
+                mouse::Cursor::Available(header),
+                bounds,
+            ),
+            (event::Status::Captured, None)
+        );
+        // inside the window the content keeps the move
+        assert_eq!(
+            state.update(
+                &moved(Point::new(400.0, 300.0)),
+                event::Status::Ignored,
+                mouse::Cursor::Available(Point::new(400.0, 300.0)),
+                bounds,
+            ),
+            (event::Status::Ignored, None)
+        );
+        assert_eq!(
+            state.update(
+                &moved(outside),
+                event::Status::Ignored,
+                mouse::Cursor::Available(outside),
+                bounds,
+            ),
+            (
+                event::Status::Captured,
+                Some(TearOffEvent::DraggedOut(outside))
+            )
+        );
+        // one tear off per press
+        assert_eq!(
+            state.update(
+                &moved(outside),
+                event::Status::Ignored,
+                mouse::Cursor::Available(outside),
+                bounds,
+            ),
+            (event::Status::Ignored, None)
+        );
+    }
+
+    #[test]
+    fn presses_the_content_captures_do_not_tear_off() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 30.0));
+        let header = Point::new(50.0, 15.0);
+        let outside = Point::new(50.0, 700.0);
+        let mut state = State {
+            window: Some(Size::new(800.0, 600.0)),
+            ..State::default()
+        };
+
+        // a button of the header takes the press
+        let _ = state.update(
+            &Event::Touch(touch::Event::FingerPressed {
+                id: touch::Finger(0),
+                position: header,
+            }),
+            event::Status::Captured,
+            mouse::Cursor::Available(header),
+            bounds,
+        );
+        assert_eq!(
+            state.update(
+                &Event::Touch(touch::Event::FingerMoved {
+                    id: touch::Finger(0),
+                    position: outside,
+                }),
+                event::Status::Ignored,
+                mouse::Cursor::Available(outside),
+                bounds,
+            ),
+            (event::Status::Ignored, None)
+        );
+
+        // released before it left the window
+        let _ = state.update(
+            &Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            event::Status::Ignored,
+            mouse::Cursor::Available(header),
+            bounds,
+        );
+        let _ = state.update(
+            &Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            event::Status::Ignored,
+            mouse::Cursor::Available(header),
+            bounds,
+        );
+        assert_eq!(
+            state.update(
+                &Event::Mouse(mouse::Event::CursorMoved { position: outside }),
+                event::Status::Ignored,
+                mouse::Cursor::Available(outside),
+                bounds,
+            ),
+            (event::Status::Ignored, None)
+        );
+    }
+
+    #[test]
+    fn redock_on_the_title_bar() {
+        let moved = Id::unique();
+        let target = Id::unique();
+        let bounds = Rectangle::new(Point::new(100.0, 100.0), Size::new(800.0, 600.0));
+        let metrics = TitleBarMetrics::DEFAULT;
+
+        assert_eq!(
+            redock(moved, Point::new(200.0, 100.0), target, bounds, metrics),
+            Some(TearOffEvent::Redock { moved, target })
+        );
+        // below the title bar
+        assert_eq!(
+            redock(moved, Point::new(200.0, 400.0), target, bounds, metrics),
+            None
+        );
+        // the grab lies left of the window
+        assert_eq!(
+            redock(moved, Point::new(0.0, 100.0), target, bounds, metrics),
+            None
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, window, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+use super::panel_buttons::Theme as PanelTheme;
+
+#[derive(Debug, Clone, Copy)]
+struct FocusState {
+    focused: bool,
+}
+
+impl Default for FocusState {
+    fn default() -> Self {
+        Self { focused: true }
+    }
+}
+
+/// draws its content with the active or inactive panel theme depending on
+/// whether the window currently has focus
+///
+/// it turns content of the [`PanelTheme`], like a
+/// [`panel_button`](crate::panel_button::panel_button), into an element of any
+/// theme
+pub struct FocusThemer<'a, Message, Renderer> {
+    content: Element<'a, Message, PanelTheme, Renderer>,
+    active: PanelTheme,
+    inactive: PanelTheme,
+    title_color: bool,
+}
+
+impl<'a, Message, Renderer> FocusThemer<'a, Message, Renderer> {
+    pub fn new(
+        active: PanelTheme,
+        inactive: PanelTheme,
+        content: impl Into<Element<'a, Message, PanelTheme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            active,
+            inactive,
+            title_color: false,
+        }
+    }
+
+    /// draw text without a color of its own in the title color of the theme,
+    /// for text titles. other content keeps the text color of the app theme
+    pub fn title_color(mut self, title_color: bool) -> Self {
+        self.title_color = title_color;
+        self
+    }
+
+    fn theme(&self, tree: &Tree) -> &PanelTheme {
+        if tree.state.downcast_ref::<FocusState>().focused {
+            &self.active
+        } else {
+            &self.inactive
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FocusThemer<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<FocusState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(FocusState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_, ref event) = event {
+            let focused = match event {
+                window::Event::Focused => Some(true),
+                window::Event::Unfocused => Some(false),
+                _ => None,
+            };
+
+            if let Some(focused) = focused {
+                let state = tree.state.downcast_mut::<FocusState>();
+
+                if state.focused != focused {
+                    state.focused = focused;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let theme = self.theme(tree);
+        let style = if self.title_color {
+            renderer::Style {
+                text_color: theme.title,
+            }
+        } else {
+            *style
+        };
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &style,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let theme = if tree.state.downcast_ref::<FocusState>().focused {
+            &self.active
+        } else {
+            &self.inactive
+        };
+
+        self.content
+            .as_widget_mut()
+            .overlay(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                translation,
+            )
+            .map(|content| overlay::Element::new(Box::new(Overlay { theme, content })))
+    }
+}
+
+/// an overlay of the content, drawn with the theme chosen by its [`FocusThemer`]
+struct Overlay<'a, Message, Renderer> {
+    theme: &'a PanelTheme,
+    content: overlay::Element<'a, Message, PanelTheme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.content.layout(renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content
+            .draw(renderer, self.theme, style, layout, cursor);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.operate(layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content
+            .on_event(event, layout, cursor, renderer, clipboard, shell)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+        self.content.is_over(layout, renderer, cursor_position)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let theme = self.theme;
+
+        self.content
+            .overlay(layout, renderer)
+            .map(|content| overlay::Element::new(Box::new(Overlay { theme, content })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FocusThemer<'a, Message, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+    Theme: 'a,
+{
+    fn from(themer: FocusThemer<'a, Message, Renderer>) -> Self {
+        Self::new(themer)
+    }
+}
@@ -1 +1,2 @@
+pub mod focus;
 pub mod panel_buttons;
@@ -1,11 +1,15 @@
+#[cfg(feature = "svg")]
+use iced::widget::svg;
 use iced::{
     color,
-    widget::{button, svg},
-    Color,
+    widget::{button, container, text},
+    Border, Color,
 };
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Theme {
     pub svg: Color,
+    pub title: Color,
 
     pub close: Color,
     pub close_active: Color,
@@ -18,11 +22,34 @@ pub struct Theme {
     pub minimize: Color,
     pub minimize_active: Color,
     pub minimize_hover: Color,
+
+    pub pin: Color,
+    pub pin_active: Color,
+    pub pin_hover: Color,
+    /// background of the pin button while the window is pinned
+    pub pinned: Color,
+    /// background of custom caption buttons that are toggled on
+    pub toggled: Color,
+
+    /// background of the caption button tooltips
+    pub tooltip: Color,
+
+    /// background of the tabs in the title bar
+    pub tab: Color,
+    /// background of the tabs and the new tab button under the pointer
+    pub tab_hover: Color,
+    /// background of the selected tab
+    pub tab_active: Color,
+
+    /// radius of the close button corners, used to round it into the
+    /// corner of the window frame
+    pub close_radius: [f32; 4],
 }
 
 impl Theme {
     pub const LIGHT: Self = Self {
         svg: Color::BLACK,
+        title: Color::BLACK,
 
         close: Color::TRANSPARENT,
         close_active: color!(255, 0, 0, 0.8),
@@ -35,10 +62,25 @@ impl Theme {
         minimize: Color::TRANSPARENT,
         minimize_hover: color!(0, 0, 0, 0.3),
         minimize_active: color!(0, 0, 0, 0.5),
+
+        pin: Color::TRANSPARENT,
+        pin_hover: color!(0, 0, 0, 0.3),
+        pin_active: color!(0, 0, 0, 0.5),
+        pinned: color!(0, 0, 0, 0.2),
+        toggled: color!(0, 0, 0, 0.2),
+
+        tooltip: color!(0xf0f0f0),
+
+        tab: Color::TRANSPARENT,
+        tab_hover: color!(0, 0, 0, 0.1),
+        tab_active: Color::WHITE,
+
+        close_radius: [0.0; 4],
     };
 
     pub const DARK: Self = Self {
         svg: Color::WHITE,
+        title: Color::WHITE,
 
         close: Color::TRANSPARENT,
         close_active: color!(255, 0, 0, 0.8),
@@ -51,7 +93,35 @@ impl Theme {
         minimize: Color::TRANSPARENT,
         minimize_hover: color!(0, 0, 0, 0.3),
         minimize_active: color!(0, 0, 0, 0.5),
+
+        pin: Color::TRANSPARENT,
+        pin_hover: color!(0, 0, 0, 0.3),
+        pin_active: color!(0, 0, 0, 0.5),
+        pinned: color!(0, 0, 0, 0.2),
+        toggled: color!(0, 0, 0, 0.2),
+
+        tooltip: color!(0x2b2b2b),
+
+        tab: Color::TRANSPARENT,
+        tab_hover: color!(255, 255, 255, 0.1),
+        tab_active: color!(0x3c3c3c),
+
+        close_radius: [0.0; 4],
     };
+
+    /// the default inactive variant of this theme, with dimmed glyphs and title
+    pub fn inactive(&self) -> Self {
+        let dim = |color: Color| Color {
+            a: color.a * 0.5,
+            ..color
+        };
+
+        Self {
+            svg: dim(self.svg),
+            title: dim(self.title),
+            ..*self
+        }
+    }
 }
 
 impl Default for Theme {
@@ -63,6 +133,7 @@ impl Default for Theme {
 /**
  * Svg
  */
+#[cfg(feature = "svg")]
 #[derive(Default)]
 pub enum Svg {
     /// No filtering to the rendered SVG.
@@ -70,6 +141,7 @@ pub enum Svg {
     Default,
 }
 
+#[cfg(feature = "svg")]
 impl svg::StyleSheet for Theme {
     type Style = Svg;
 
@@ -82,12 +154,20 @@ impl svg::StyleSheet for Theme {
     }
 }
 
+/// style of a caption button, [`Button::Custom`] and [`Button::Toggled`] are
+/// for buttons of the app that should look like the panel buttons
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum Button {
     #[default]
     Restore,
     Minimize,
     Close,
+    Pin,
+    /// the pin button while the window is pinned
+    Pinned,
+    Custom,
+    /// a custom button that is toggled on
+    Toggled,
 }
 
 impl button::StyleSheet for Theme {
@@ -97,6 +177,10 @@ impl button::StyleSheet for Theme {
         match style {
             Button::Close => button::Appearance {
                 background: Some(self.close.into()),
+                border: Border {
+                    radius: self.close_radius.into(),
+                    ..Border::default()
+                },
                 ..button::Appearance::default()
             },
             Button::Restore => button::Appearance {
@@ -107,6 +191,22 @@ impl button::StyleSheet for Theme {
                 background: Some(self.minimize.into()),
                 ..button::Appearance::default()
             },
+            Button::Pin => button::Appearance {
+                background: Some(self.pin.into()),
+                ..button::Appearance::default()
+            },
+            Button::Pinned => button::Appearance {
+                background: Some(self.pinned.into()),
+                ..button::Appearance::default()
+            },
+            Button::Custom => button::Appearance {
+                background: Some(self.restore.into()),
+                ..button::Appearance::default()
+            },
+            Button::Toggled => button::Appearance {
+                background: Some(self.toggled.into()),
+                ..button::Appearance::default()
+            },
         }
     }
 
@@ -126,6 +226,14 @@ impl button::StyleSheet for Theme {
                 background: Some(self.minimize_hover.into()),
                 ..active
             },
+            Button::Pin | Button::Pinned => button::Appearance {
+                background: Some(self.pin_hover.into()),
+                ..active
+            },
+            Button::Custom | Button::Toggled => button::Appearance {
+                background: Some(self.restore_hover.into()),
+                ..active
+            },
         }
     }
 
@@ -145,6 +253,14 @@ impl button::StyleSheet for Theme {
                 background: Some(self.minimize_active.into()),
                 ..active
             },
+            Button::Pin | Button::Pinned => button::Appearance {
+                background: Some(self.pin_active.into()),
+                ..active
+            },
+            Button::Custom | Button::Toggled => button::Appearance {
+                background: Some(self.restore_active.into()),
+                ..active
+            },
         }
     }
 
@@ -160,3 +276,65 @@ impl button::StyleSheet for Theme {
         }
     }
 }
+
+/// style of the containers drawn with the panel theme
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Container {
+    /// no background
+    #[default]
+    Transparent,
+    /// the tooltips of the caption buttons
+    Tooltip,
+}
+
+impl container::StyleSheet for Theme {
+    type Style = Container;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        match style {
+            Container::Transparent => container::Appearance::default(),
+            Container::Tooltip => container::Appearance {
+                text_color: Some(self.title),
+                background: Some(self.tooltip.into()),
+                border: Border {
+                    color: Color {
+                        a: 0.2,
+                        ..self.title
+                    },
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..container::Appearance::default()
+            },
+        }
+    }
+}
+
+/// style of the text drawn with the panel theme
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Text {
+    /// the title color of the theme
+    #[default]
+    Title,
+    /// a color of its own, like the color of a title style
+    Color(Color),
+}
+
+impl From<Color> for Text {
+    fn from(color: Color) -> Self {
+        Text::Color(color)
+    }
+}
+
+impl text::StyleSheet for Theme {
+    type Style = Text;
+
+    fn appearance(&self, style: Self::Style) -> text::Appearance {
+        text::Appearance {
+            color: Some(match style {
+                Text::Title => self.title,
+                Text::Color(color) => color,
+            }),
+        }
+    }
+}
@@ -1,27 +1,212 @@
-use crate::drag_window::DragWindow;
-use crate::helpers::reizer_operation::{SetState, RESIZER_ID};
-use crate::resizer::{resizer, ResizeEvent};
-use crate::{svgs, WindowHandler};
+use crate::drag_window::{drag_area, DragWindow, Gestures, TitleStyle};
+use crate::frame::Frame;
+use crate::glyphs::Glyph;
+use crate::helpers::reizer_operation::{resizer_id, SetState, ToggleShade};
+pub use crate::panel_button::CaptionIcon;
+use crate::panel_button::PanelButtons;
+use crate::resizer::{resizer, ResizeArea, ResizeEvent};
+#[cfg(feature = "svg")]
+use crate::svgs::IconSet;
+use crate::tabs::TabStrip;
+use crate::theming::focus::FocusThemer;
+use crate::{IconRenderer, WindowHandler};
 use iced::advanced::graphics::core::Element;
 
-use iced::widget::themer;
 use iced::{
-    widget::{button, container, horizontal_space, row, svg},
+    alignment,
+    widget::{column, container, text, tooltip, Column, Row},
     window::{self, Id},
-    Command, Length, Point, Rectangle, Size,
+    Alignment, Command, Length, Point, Rectangle, Size,
 };
 
+/// size of the subtitle relative to the title
+const SUBTITLE_SCALE: f32 = 0.8;
+/// size of the subtitle when the title has the default size
+const DEFAULT_SUBTITLE_SIZE: f32 = 12.0;
+
+/// sizes of the title bar and its panel buttons
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TitleBarMetrics {
+    /// height of the title bar
+    pub height: f32,
+    /// width of each panel button
+    pub button_width: f32,
+    /// height of the icons in the panel buttons
+    pub icon_size: f32,
+}
+
+impl TitleBarMetrics {
+    pub const DEFAULT: Self = Self {
+        height: 35.0,
+        button_width: 50.0,
+        icon_size: 30.0,
+    };
+
+    /// small title bar for tool windows
+    pub const COMPACT: Self = Self {
+        height: 24.0,
+        button_width: 36.0,
+        icon_size: 20.0,
+    };
+
+    /// large title bar that is easy to hit on touch screens
+    pub const TOUCH: Self = Self {
+        height: 48.0,
+        button_width: 68.0,
+        icon_size: 40.0,
+    };
+
+    /// metrics for a title bar of the given height, with the buttons and
+    /// icons scaled to it
+    pub fn with_height(height: f32) -> Self {
+        let scale = height / Self::DEFAULT.height;
+
+        Self {
+            height,
+            button_width: Self::DEFAULT.button_width * scale,
+            icon_size: Self::DEFAULT.icon_size * scale,
+        }
+    }
+}
+
+impl Default for TitleBarMetrics {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// order of the title bar contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutDirection {
+    /// content on the left, panel buttons on the right
+    #[default]
+    LeftToRight,
+    /// mirrored for right to left languages, content on the right and panel
+    /// buttons on the left. resizing still follows the physical window edges
+    RightToLeft,
+}
+
 #[derive(Debug, Clone)]
 pub enum TitleEvents {
     Minimize,
     Restore,
     Close,
+    /// roll the window up to its title bar or unroll it
+    Shade,
+    /// keep the window above other windows or stop keeping it there, the app
+    /// stores the state for [`Window::pinned`]
+    Pin(bool),
+}
+
+/// what a gesture on the title bar does
+///
+/// there is no action to lower the window below other windows because iced
+/// has no command for it, use [`TitleBarAction::Message`] with a message of the
+/// app that lowers the window through the platform instead
+#[derive(Debug, Clone, Default)]
+pub enum TitleBarAction<Message> {
+    #[default]
+    Nothing,
+    ToggleMaximize,
+    Minimize,
+    Close,
+    /// roll the window up to its title bar or unroll it
+    ToggleShade,
+    /// publish a message of the app
+    Message(Message),
+}
+
+impl<Message: WindowHandler> TitleBarAction<Message> {
+    fn message(self, window: Id) -> Option<Message> {
+        let event = match self {
+            TitleBarAction::Nothing => return None,
+            TitleBarAction::ToggleMaximize => TitleEvents::Restore,
+            TitleBarAction::Minimize => TitleEvents::Minimize,
+            TitleBarAction::Close => TitleEvents::Close,
+            TitleBarAction::ToggleShade => TitleEvents::Shade,
+            TitleBarAction::Message(message) => return Some(message),
+        };
+
+        Some(Message::event_handler(
+            WindowEvents::TitleEvent(event).for_window(window),
+        ))
+    }
+}
+
+/// the actions of the gestures on the title bar, by default a double click
+/// toggles maximize
+#[derive(Debug, Clone)]
+pub struct TitleBarActions<Message> {
+    pub double_click: TitleBarAction<Message>,
+    pub middle_click: TitleBarAction<Message>,
+    pub right_click: TitleBarAction<Message>,
+    pub scroll_up: TitleBarAction<Message>,
+    pub scroll_down: TitleBarAction<Message>,
+}
+
+impl<Message> TitleBarActions<Message> {
+    pub fn double_click(mut self, action: TitleBarAction<Message>) -> Self {
+        self.double_click = action;
+        self
+    }
+
+    pub fn middle_click(mut self, action: TitleBarAction<Message>) -> Self {
+        self.middle_click = action;
+        self
+    }
+
+    pub fn right_click(mut self, action: TitleBarAction<Message>) -> Self {
+        self.right_click = action;
+        self
+    }
+
+    pub fn scroll_up(mut self, action: TitleBarAction<Message>) -> Self {
+        self.scroll_up = action;
+        self
+    }
+
+    pub fn scroll_down(mut self, action: TitleBarAction<Message>) -> Self {
+        self.scroll_down = action;
+        self
+    }
+}
+
+impl<Message> Default for TitleBarActions<Message> {
+    fn default() -> Self {
+        Self {
+            double_click: TitleBarAction::ToggleMaximize,
+            middle_click: TitleBarAction::Nothing,
+            right_click: TitleBarAction::Nothing,
+            scroll_up: TitleBarAction::Nothing,
+            scroll_down: TitleBarAction::Nothing,
+        }
+    }
+}
+
+impl<Message: WindowHandler> TitleBarActions<Message> {
+    /// the gestures that run the actions on the window
+    fn gestures(self, window: Id) -> Gestures<Message> {
+        Gestures {
+            double_click: self.double_click.message(window),
+            middle_click: self.middle_click.message(window),
+            right_click: self.right_click.message(window),
+            scroll_up: self.scroll_up.message(window),
+            scroll_down: self.scroll_down.message(window),
+        }
+    }
+}
+
+impl<Message: WindowHandler> From<TitleBarActions<Message>> for Gestures<Message> {
+    fn from(actions: TitleBarActions<Message>) -> Self {
+        actions.gestures(Id::MAIN)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum UpdateResizerState {
     Size(Size),
     Position(Option<Point>),
+    Maximized(bool),
 }
 
 #[derive(Debug, Clone)]
@@ -30,24 +215,135 @@ pub enum WindowEvents {
     TitleEvent(TitleEvents),
     UpdateResizerState(UpdateResizerState),
     DragWindow,
+    /// an event of a window other than the main window, see [`Window::id`].
+    /// the [`TitleEvents::Pin`] of such a window is found inside it
+    Window(Id, Box<WindowEvents>),
+}
+
+impl WindowEvents {
+    /// the event of the window, events of the main window are left as they are
+    pub fn for_window(self, window: Id) -> Self {
+        if window == Id::MAIN {
+            self
+        } else {
+            WindowEvents::Window(window, Box::new(self))
+        }
+    }
+}
+
+/// an extra button of the app in the caption row, styled like the panel buttons
+#[derive(Debug, Clone)]
+pub struct CaptionButton<Message> {
+    icon: CaptionIcon,
+    message: Message,
+    tooltip: Option<String>,
+    toggled: bool,
+}
+
+impl<Message> CaptionButton<Message> {
+    pub fn new(icon: impl Into<CaptionIcon>, message: Message) -> Self {
+        CaptionButton {
+            icon: icon.into(),
+            message,
+            tooltip: None,
+            toggled: false,
+        }
+    }
+
+    /// text shown below the button while it is hovered
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// draw the button as pressed in, the app keeps track of the state
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = toggled;
+        self
+    }
 }
 
 /// window decorations with title bar, menu bar, and resizer
 pub struct Window<'a, Message, Theme, Renderer> {
     /// content on the left of the menu bar
     content: Option<Element<'a, Message, Theme, Renderer>>,
+    /// widgets packed at the start of the menu bar after the content
+    start: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// widgets packed at the end of the menu bar before the panel buttons
+    end: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// draggable space around the packed widgets
+    pack_spacing: f32,
+    /// content of the window below the menu bar
+    body: Option<Element<'a, Message, Theme, Renderer>>,
     /// put title into the middle of the menu bar
     title: Option<String>,
+    /// font, color and placement of the title
+    title_style: TitleStyle,
+    /// element in place of the text title
+    title_element: Option<Element<'a, Message, Theme, Renderer>>,
+    /// smaller text below the title
+    subtitle: Option<String>,
     /// theme for the panel buttons
     panel_theme: crate::PanelTheme,
+    /// theme for the panel buttons and title while the window is unfocused
+    inactive_panel_theme: Option<crate::PanelTheme>,
+    /// rounded frame with border and shadow, needs a transparent window
+    frame: Option<Frame>,
+    /// where the resize hit zones are placed
+    resize_area: ResizeArea,
+    /// icons of the panel buttons
+    #[cfg(feature = "svg")]
+    icons: IconSet,
+    /// sizes of the title bar and the panel buttons
+    metrics: TitleBarMetrics,
+    /// order of the title bar contents
+    direction: LayoutDirection,
+    /// pointer movement before a press on the title bar drags the window
+    drag_threshold: Option<f32>,
+    /// presses the body ignores drag the window
+    movable_by_background: bool,
+    /// what clicks and scrolling on the title bar do
+    title_bar_actions: TitleBarActions<Message>,
+    /// show a button that keeps the window on top
+    pin_button: bool,
+    /// the window is kept on top, the pin button is drawn pressed in
+    pinned: bool,
+    /// buttons of the app in front of the panel buttons
+    caption_buttons: Vec<CaptionButton<Message>>,
+    /// tabs in place of the title
+    tabs: Option<TabStrip<'a, Message>>,
+    /// the window the decorations are drawn in
+    id: Id,
 }
 
 impl<'a, Message, Theme, Renderer> Window<'a, Message, Theme, Renderer> {
     pub fn new() -> Self {
         Window {
             content: None,
+            start: Vec::new(),
+            end: Vec::new(),
+            pack_spacing: 0.0,
+            body: None,
             title: None,
+            title_style: TitleStyle::default(),
+            title_element: None,
+            subtitle: None,
             panel_theme: crate::PanelTheme::default(),
+            inactive_panel_theme: None,
+            frame: None,
+            resize_area: ResizeArea::default(),
+            #[cfg(feature = "svg")]
+            icons: IconSet::default(),
+            metrics: TitleBarMetrics::default(),
+            direction: LayoutDirection::default(),
+            drag_threshold: None,
+            movable_by_background: false,
+            title_bar_actions: TitleBarActions::default(),
+            pin_button: false,
+            pinned: false,
+            caption_buttons: Vec::new(),
+            tabs: None,
+            id: Id::MAIN,
         }
     }
 
@@ -56,80 +352,409 @@ impl<'a, Message, Theme, Renderer> Window<'a, Message, Theme, Renderer> {
         self
     }
 
+    /// pack a widget at the start of the menu bar, after the content and the
+    /// widgets packed before it
+    pub fn pack_start(mut self, widget: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.start.push(widget.into());
+        self
+    }
+
+    /// pack a widget at the end of the menu bar, before the panel buttons and
+    /// the widgets packed before it
+    pub fn pack_end(mut self, widget: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.end.push(widget.into());
+        self
+    }
+
+    /// space around the packed widgets, it drags the window like the title
+    pub fn pack_spacing(mut self, spacing: f32) -> Self {
+        self.pack_spacing = spacing;
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
     pub fn title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
         self
     }
 
-    pub fn panel_theme(
+    /// put any element into the title slot instead of the text title, e.g. breadcrumbs
+    /// or a dropdown. presses the element does not capture still drag the window
+    pub fn title_element(
         mut self,
-        panel_theme: crate::PanelTheme,
+        title_element: impl Into<Element<'a, Message, Theme, Renderer>>,
     ) -> Self {
+        self.title_element = Some(title_element.into());
+        self
+    }
+
+    /// the center widget of the menu bar, same as [`Window::title_element`]
+    pub fn center(self, center: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.title_element(center)
+    }
+
+    /// smaller text below the title, the title and subtitle are drawn with the
+    /// panel theme and the title style like the text title
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn title_style(mut self, title_style: TitleStyle) -> Self {
+        self.title_style = title_style;
+        self
+    }
+
+    pub fn panel_theme(mut self, panel_theme: crate::PanelTheme) -> Self {
         self.panel_theme = panel_theme;
         self
     }
 
+    /// overrides the theme used while the window is unfocused,
+    /// defaults to [`PanelTheme::inactive`](crate::PanelTheme::inactive) of the panel theme
+    pub fn inactive_panel_theme(mut self, panel_theme: crate::PanelTheme) -> Self {
+        self.inactive_panel_theme = Some(panel_theme);
+        self
+    }
+
+    /// draw a rounded frame with a border and a shadow around the window,
+    /// see [`Frame`] for the window settings it needs
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// where to put the resize hit zones, [`ResizeArea::Outside`] keeps the
+    /// whole window content interactive
+    pub fn resize_area(mut self, resize_area: ResizeArea) -> Self {
+        self.resize_area = resize_area;
+        self
+    }
+
+    /// icons of the panel buttons, either a bundled [`IconPack`](crate::svgs::IconPack)
+    /// or a custom [`IconSet`]
+    #[cfg(feature = "svg")]
+    pub fn icons(mut self, icons: impl Into<IconSet>) -> Self {
+        self.icons = icons.into();
+        self
+    }
+
+    /// sizes of the title bar and the panel buttons, see
+    /// [`TitleBarMetrics::COMPACT`] and [`TitleBarMetrics::TOUCH`]
+    pub fn metrics(mut self, metrics: TitleBarMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// height of the title bar, the panel buttons scale with it
+    pub fn title_bar_height(mut self, height: f32) -> Self {
+        self.metrics = TitleBarMetrics::with_height(height);
+        self
+    }
+
+    /// width of each panel button
+    pub fn button_width(mut self, width: f32) -> Self {
+        self.metrics.button_width = width;
+        self
+    }
+
+    /// mirror the title bar for right to left languages, the title alignment
+    /// is mirrored as well
+    pub fn direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// only drag the window once the pointer moved this far on the title bar,
+    /// so clicks on the title bar do not grab the pointer
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = Some(threshold);
+        self
+    }
+
+    /// move the window by dragging anywhere on the body that its widgets do
+    /// not capture, like small utility windows and huds
+    pub fn movable_by_background(mut self, movable: bool) -> Self {
+        self.movable_by_background = movable;
+        self
+    }
+
+    /// what double, middle and right clicks and scrolling on the title bar do
+    pub fn title_bar_actions(mut self, actions: TitleBarActions<Message>) -> Self {
+        self.title_bar_actions = actions;
+        self
+    }
+
+    /// show a pin button next to minimize that keeps the window above other
+    /// windows while it is pressed in
+    ///
+    /// the app owns the pinned state like the state of any other widget: the
+    /// button publishes [`TitleEvents::Pin`] with the new state, [`event_handler`]
+    /// only changes the window level, and the button is drawn from
+    /// [`Window::pinned`]. an app that passes the event on without storing it
+    /// pins the window but keeps showing the unpinned glyph
+    pub fn pin_button(mut self, pin_button: bool) -> Self {
+        self.pin_button = pin_button;
+        self
+    }
+
+    /// draw the pin button as pressed in, the app keeps track of the state
+    /// from the [`TitleEvents::Pin`] events it passes to [`event_handler`]
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    /// add a button of the app in front of the panel buttons
+    pub fn caption_button(mut self, caption_button: CaptionButton<Message>) -> Self {
+        self.caption_buttons.push(caption_button);
+        self
+    }
+
+    /// put browser style tabs into the title bar instead of the title, the
+    /// gaps around the tabs drag the window and take the title bar actions
+    pub fn tabs(mut self, tabs: TabStrip<'a, Message>) -> Self {
+        self.tabs = Some(tabs);
+        self
+    }
+
+    /// the window the decorations are drawn in, the main window by default.
+    /// the events of other windows come wrapped in [`WindowEvents::Window`] so
+    /// [`event_handler`] runs their commands on them, a window opened with
+    /// [`open_window`](crate::tear_off::open_window) also needs [`init_for`]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = id;
+        self
+    }
+
     fn view(self) -> Element<'a, Message, Theme, Renderer>
     where
         Message: 'a + Clone + WindowHandler,
         Renderer: 'a
-            + iced::advanced::svg::Renderer
+            + IconRenderer
             + iced::advanced::Renderer
-            + iced::advanced::text::Renderer,
+            + iced::advanced::text::Renderer<Font = iced::Font>,
         Theme: 'a
             + iced::widget::button::StyleSheet
             + iced::widget::container::StyleSheet
-            + iced::widget::text::StyleSheet
-            + iced::widget::svg::StyleSheet,
+            + iced::widget::text::StyleSheet,
     {
-        let title_bar_buttons = row![
-            button(svg(svgs::MINIMIZE_SVG.clone()).height(30.0))
-                .style(crate::theming::panel_buttons::Button::Minimize)
-                .width(50.0)
-                // .style(menu_theme::Button::OtherMenu)
-                .on_press(Message::event_handler(WindowEvents::TitleEvent(
-                    TitleEvents::Minimize
-                ))),
-            button(svg(svgs::RESTORE_SVG.clone()).height(30.0))
-                .style(crate::theming::panel_buttons::Button::Restore)
-                .width(50.0)
-                // .style(menu_theme::Button::OtherMenu)
-                .on_press(Message::event_handler(WindowEvents::TitleEvent(
-                    TitleEvents::Restore
-                ))),
-            button(svg(svgs::CLOSE_SVG.clone()).height(30.0))
-                .style(crate::theming::panel_buttons::Button::Close)
-                .width(50.0)
-                // .style(menu_theme::Button::Close)
-                .on_press(Message::event_handler(WindowEvents::TitleEvent(
-                    TitleEvents::Close
-                ))),
+        let metrics = self.metrics;
+        let id = self.id;
+        // the messages of the window, wrapped when it is not the main window
+        let message = move |event: WindowEvents| Message::event_handler(event.for_window(id));
+
+        let panel_buttons = PanelButtons {
+            metrics,
+            #[cfg(feature = "svg")]
+            icons: self.icons,
+        };
+
+        let rtl = self.direction == LayoutDirection::RightToLeft;
+
+        let mut title_bar_buttons: Vec<Element<'a, Message, crate::PanelTheme, Renderer>> = vec![
+            panel_buttons
+                .button(Glyph::Minimize)
+                .on_press(message(WindowEvents::TitleEvent(TitleEvents::Minimize)))
+                .into(),
+            panel_buttons
+                .button(Glyph::Restore)
+                .on_press(message(WindowEvents::TitleEvent(TitleEvents::Restore)))
+                .into(),
+            panel_buttons
+                .button(Glyph::Close)
+                .on_press(message(WindowEvents::TitleEvent(TitleEvents::Close)))
+                .into(),
         ];
+        if self.pin_button {
+            // the pinned glyph is drawn pressed in and unpins the window
+            let glyph = if self.pinned {
+                Glyph::Unpin
+            } else {
+                Glyph::Pin
+            };
+
+            title_bar_buttons.insert(
+                0,
+                panel_buttons
+                    .button(glyph)
+                    .on_press(message(WindowEvents::TitleEvent(TitleEvents::Pin(
+                        !self.pinned,
+                    ))))
+                    .into(),
+            );
+        }
+        let caption_buttons = self.caption_buttons.into_iter().map(|caption_button| {
+            let style = if caption_button.toggled {
+                crate::PanelButton::Toggled
+            } else {
+                crate::PanelButton::Custom
+            };
+            let button = panel_buttons
+                .button(caption_button.icon)
+                .style(style)
+                .on_press(caption_button.message);
+
+            match caption_button.tooltip {
+                Some(tip) => tooltip(button, text(tip), tooltip::Position::Bottom)
+                    .padding(4)
+                    .style(crate::theming::panel_buttons::Container::Tooltip)
+                    .into(),
+                None => button.into(),
+            }
+        });
+        title_bar_buttons.splice(0..0, caption_buttons);
+
+        if rtl {
+            title_bar_buttons.reverse();
+        }
+
+        // round the close button into the corner of the frame
+        let radius = self
+            .frame
+            .map_or(0.0, |frame| (frame.radius - frame.border_width).max(0.0));
+        let close_radius = if rtl {
+            [radius, 0.0, 0.0, 0.0]
+        } else {
+            [0.0, radius, 0.0, 0.0]
+        };
+        let panel_theme = crate::PanelTheme {
+            close_radius,
+            ..self.panel_theme
+        };
+        let inactive_panel_theme = crate::PanelTheme {
+            close_radius,
+            ..self
+                .inactive_panel_theme
+                .unwrap_or_else(|| self.panel_theme.inactive())
+        };
 
-        let left_content = if let Some(content) = self.content {
-            content
+        // the leading side of the title bar is on the right in right to left layouts
+        let title_style = if rtl {
+            TitleStyle {
+                alignment: match self.title_style.alignment {
+                    alignment::Horizontal::Left => alignment::Horizontal::Right,
+                    alignment::Horizontal::Center => alignment::Horizontal::Center,
+                    alignment::Horizontal::Right => alignment::Horizontal::Left,
+                },
+                ..self.title_style
+            }
         } else {
-            horizontal_space().into()
+            self.title_style
+        };
+
+        let drag_message = message(WindowEvents::DragWindow);
+        let spacing = self.pack_spacing;
+        let drag_threshold = self.drag_threshold;
+        let gestures = self.title_bar_actions.gestures(id);
+        // gaps between the packed widgets still drag the window
+        let pack = |items: Vec<Element<'a, Message, Theme, Renderer>>| {
+            let mut packed: Vec<Element<'a, Message, Theme, Renderer>> =
+                Vec::with_capacity(items.len() * 2);
+            for item in items {
+                packed.push(
+                    drag_space(spacing, drag_message.clone(), drag_threshold, &gestures).into(),
+                );
+                packed.push(item);
+            }
+            packed
         };
 
-        let menu_bar = container(row![
-            left_content,
-            DragWindow::with_width(
+        let mut menu_bar: Vec<Element<'a, Message, Theme, Renderer>> =
+            pack(self.content.into_iter().chain(self.start).collect());
+
+        // a text title, a subtitle and tabs are drawn with the panel theme, a title
+        // element with the app theme
+        menu_bar.push(match (self.tabs, self.title_element, self.subtitle) {
+            (Some(tabs), _, _) => {
+                let mut tabs = tabs
+                    .on_drag(drag_message.clone())
+                    .gestures(gestures.clone())
+                    .panel_buttons(panel_buttons.clone());
+                if let Some(threshold) = drag_threshold {
+                    tabs = tabs.drag_threshold(threshold);
+                }
+
+                FocusThemer::new(panel_theme, inactive_panel_theme, tabs).into()
+            }
+            (None, Some(center), _) => drag_space(
                 Length::Fill,
-                Message::event_handler(WindowEvents::DragWindow)
+                drag_message.clone(),
+                drag_threshold,
+                &gestures,
+            )
+            .title_style(title_style)
+            .content(center)
+            .into(),
+            (None, None, Some(subtitle)) => FocusThemer::new(
+                panel_theme,
+                inactive_panel_theme,
+                drag_space(
+                    Length::Fill,
+                    drag_message.clone(),
+                    drag_threshold,
+                    &gestures,
+                )
+                .title_style(title_style)
+                .content(title_block(self.title, subtitle, &title_style)),
             )
-            .set_title(self.title),
-            themer(
-                self.panel_theme,
-                title_bar_buttons
+            .into(),
+            (None, None, None) => FocusThemer::new(
+                panel_theme,
+                inactive_panel_theme,
+                drag_space(
+                    Length::Fill,
+                    drag_message.clone(),
+                    drag_threshold,
+                    &gestures,
+                )
+                .title_style(title_style)
+                .set_title(self.title),
             )
-        ])
-        .height(35.0);
+            .title_color(true)
+            .into(),
+        });
 
-        let window = resizer(menu_bar, |e| {
-            Message::event_handler(WindowEvents::ResizeEvent(e))
-        })
-        .id(RESIZER_ID.clone());
+        let mut end = pack(self.end);
+        end.reverse();
+        menu_bar.extend(end);
+
+        menu_bar.push(
+            FocusThemer::new(
+                panel_theme,
+                inactive_panel_theme,
+                Row::with_children(title_bar_buttons),
+            )
+            .into(),
+        );
+        if rtl {
+            menu_bar.reverse();
+        }
+
+        let menu_bar = container(Row::with_children(menu_bar)).height(metrics.height);
+
+        let content: Element<'a, Message, Theme, Renderer> = match self.body {
+            Some(body) if self.movable_by_background => {
+                let mut body = drag_area(body, drag_message);
+                if let Some(threshold) = drag_threshold {
+                    body = body.drag_threshold(threshold);
+                }
+
+                column![menu_bar, body].into()
+            }
+            Some(body) => column![menu_bar, body].into(),
+            None => menu_bar.into(),
+        };
+
+        let window = resizer(content, move |e| message(WindowEvents::ResizeEvent(e)))
+            .id(resizer_id(id))
+            .frame(self.frame)
+            .area(self.resize_area)
+            .shade_height(metrics.height);
         window.into()
     }
 }
@@ -139,40 +764,137 @@ impl<'a, Message, Theme, Renderer> Into<Element<'a, Message, Theme, Renderer>>
 where
     Message: 'a + Clone + WindowHandler,
     Renderer: 'a
-        + iced::advanced::svg::Renderer
+        + IconRenderer
         + iced::advanced::Renderer
-        + iced::advanced::text::Renderer,
+        + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: 'a
         + iced::widget::button::StyleSheet
         + iced::widget::container::StyleSheet
-        + iced::widget::text::StyleSheet
-        + iced::widget::svg::StyleSheet,
+        + iced::widget::text::StyleSheet,
 {
     fn into(self) -> Element<'a, Message, Theme, Renderer> {
         self.view()
     }
 }
 
+/// the title with the subtitle below it, drawn with the panel theme in the
+/// font, color and alignment of the title style
+fn title_block<'a, Message, Renderer>(
+    title: Option<String>,
+    subtitle: String,
+    style: &TitleStyle,
+) -> Element<'a, Message, crate::PanelTheme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
+{
+    let font = style.text_font();
+    let color = style
+        .color
+        .map_or(crate::theming::panel_buttons::Text::Title, Into::into);
+    let line = |content: String| -> iced::widget::Text<'a, crate::PanelTheme, Renderer> {
+        let line = text(content).style(color);
+
+        match font {
+            Some(font) => line.font(font),
+            None => line,
+        }
+    };
+
+    let mut lines: Vec<Element<'a, Message, crate::PanelTheme, Renderer>> = Vec::with_capacity(2);
+    if let Some(title) = title {
+        let title = line(title);
+
+        lines.push(match style.size {
+            Some(size) => title.size(size).into(),
+            None => title.into(),
+        });
+    }
+    let subtitle_size = style
+        .size
+        .map_or(DEFAULT_SUBTITLE_SIZE, |size| size.0 * SUBTITLE_SCALE);
+    lines.push(line(subtitle).size(subtitle_size).into());
+
+    Column::with_children(lines)
+        .align_items(match style.alignment {
+            alignment::Horizontal::Left => Alignment::Start,
+            alignment::Horizontal::Center => Alignment::Center,
+            alignment::Horizontal::Right => Alignment::End,
+        })
+        .into()
+}
+
+/// a draggable part of the title bar
+fn drag_space<'a, Message, Theme, Renderer>(
+    width: impl Into<Length>,
+    message: Message,
+    threshold: Option<f32>,
+    gestures: &Gestures<Message>,
+) -> DragWindow<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+{
+    let space = DragWindow::with_width(width, message).gestures(gestures.clone());
+
+    match threshold {
+        Some(threshold) => space.drag_threshold(threshold),
+        None => space,
+    }
+}
+
 pub fn init<Message: WindowHandler>() -> Command<Message> {
+    init_for(Id::MAIN)
+}
+
+/// [`init`] for a window other than the main window, run it once the app sees
+/// the window opened
+pub fn init_for<Message: WindowHandler>(window: Id) -> Command<Message> {
     Command::batch(vec![
-        window::fetch_size(Id::MAIN, |size| {
-            Message::event_handler(WindowEvents::UpdateResizerState(UpdateResizerState::Size(
-                size,
-            )))
+        window::fetch_size(window, move |size| {
+            Message::event_handler(
+                WindowEvents::UpdateResizerState(UpdateResizerState::Size(size)).for_window(window),
+            )
         }),
-        window::fetch_position(Id::MAIN, |position| {
-            Message::event_handler(WindowEvents::UpdateResizerState(
-                UpdateResizerState::Position(position),
-            ))
+        window::fetch_position(window, move |position| {
+            Message::event_handler(
+                WindowEvents::UpdateResizerState(UpdateResizerState::Position(position))
+                    .for_window(window),
+            )
         }),
     ])
 }
 
-pub fn event_handler<Message>(event: WindowEvents) -> Command<Message> {
+/// shades the window to its title bar, the next call restores the height the
+/// window had before. while shaded the window can only be resized horizontally
+///
+/// a maximized window is not shaded, a shaded window that gets maximized is
+/// shaded again when it is unmaximized. the window size is read from the
+/// layout of the [`Window`], so this works before [`init`] reports it
+pub fn toggle_shade<Message: WindowHandler>() -> Command<Message> {
+    toggle_shade_for(Id::MAIN)
+}
+
+/// [`toggle_shade`] for a window other than the main window
+pub fn toggle_shade_for<Message: WindowHandler>(window: Id) -> Command<Message> {
+    Command::widget(ToggleShade::new(window))
+}
+
+/// runs the window commands for the events of the [`Window`], `Message` has to
+/// implement [`WindowHandler`] to read back the maximized state of the window
+pub fn event_handler<Message: WindowHandler>(event: WindowEvents) -> Command<Message> {
+    event_handler_for(Id::MAIN, event)
+}
+
+/// [`event_handler`] for the events of a window other than the main window,
+/// events wrapped in [`WindowEvents::Window`] run on their own window
+pub fn event_handler_for<Message: WindowHandler>(
+    window: Id,
+    event: WindowEvents,
+) -> Command<Message> {
     match event {
         WindowEvents::ResizeEvent(re) => match re {
             ResizeEvent::ResizeXY(size) => {
-                return window::resize(Id::MAIN, size);
+                return window::resize(window, size);
             }
             ResizeEvent::ResizeWindow(rec) => {
                 let Rectangle {
@@ -182,35 +904,112 @@ pub fn event_handler<Message>(event: WindowEvents) -> Command<Message> {
                     height,
                 } = rec;
                 return Command::batch(vec![
-                    window::resize(Id::MAIN, Size::new(width, height)),
-                    window::move_to(Id::MAIN, Point::new(x, y)),
+                    window::resize(window, Size::new(width, height)),
+                    window::move_to(window, Point::new(x, y)),
                 ]);
             }
+            ResizeEvent::Resized(_) => {
+                return window::fetch_maximized(window, move |maximized| {
+                    Message::event_handler(
+                        WindowEvents::UpdateResizerState(UpdateResizerState::Maximized(maximized))
+                            .for_window(window),
+                    )
+                });
+            }
         },
         WindowEvents::TitleEvent(te) => match te {
             TitleEvents::Minimize => {
-                return window::minimize(Id::MAIN, true);
+                return window::minimize(window, true);
             }
             TitleEvents::Restore => {
-                return window::toggle_maximize(Id::MAIN);
+                return window::toggle_maximize(window);
             }
             TitleEvents::Close => {
-                return window::close(Id::MAIN);
+                return window::close(window);
+            }
+            TitleEvents::Shade => {
+                return toggle_shade_for(window);
+            }
+            TitleEvents::Pin(pinned) => {
+                let level = if pinned {
+                    window::Level::AlwaysOnTop
+                } else {
+                    window::Level::Normal
+                };
+
+                return window::change_level(window, level);
             }
         },
         WindowEvents::UpdateResizerState(urs) => match urs {
             UpdateResizerState::Size(size) => {
-                return Command::widget(SetState::with_size(size));
+                return Command::widget(SetState::with_size(window, size));
             }
             UpdateResizerState::Position(position) => {
                 if let Some(position) = position {
-                    return Command::widget(SetState::with_position(position));
+                    return Command::widget(SetState::with_position(window, position));
                 }
             }
+            UpdateResizerState::Maximized(maximized) => {
+                return Command::widget(SetState::with_maximized(window, maximized));
+            }
         },
         WindowEvents::DragWindow => {
-            return window::drag(Id::MAIN);
+            return window::drag(window);
+        }
+        WindowEvents::Window(window, event) => {
+            return event_handler_for(window, *event);
         }
     }
     Command::none()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Message(WindowEvents);
+
+    impl WindowHandler for Message {
+        fn event_handler(event: WindowEvents) -> Self {
+            Message(event)
+        }
+    }
+
+    #[test]
+    fn events_of_other_windows_are_wrapped() {
+        let other = Id::unique();
+
+        assert!(matches!(
+            WindowEvents::DragWindow.for_window(Id::MAIN),
+            WindowEvents::DragWindow
+        ));
+        assert!(matches!(
+            WindowEvents::DragWindow.for_window(other),
+            WindowEvents::Window(id, event)
+                if id == other && matches!(*event, WindowEvents::DragWindow)
+        ));
+    }
+
+    #[test]
+    fn gestures_run_on_their_window() {
+        let other = Id::unique();
+        let gestures = TitleBarActions::default()
+            .middle_click(TitleBarAction::Minimize)
+            .gestures(other);
+
+        assert!(matches!(
+            gestures.double_click,
+            Some(Message(WindowEvents::Window(id, event)))
+                if id == other
+                    && matches!(*event, WindowEvents::TitleEvent(TitleEvents::Restore))
+        ));
+        assert!(matches!(
+            gestures.middle_click,
+            Some(Message(WindowEvents::Window(id, event)))
+                if id == other
+                    && matches!(*event, WindowEvents::TitleEvent(TitleEvents::Minimize))
+        ));
+        assert!(gestures.right_click.is_none());
+    }
+}